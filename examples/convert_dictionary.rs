@@ -0,0 +1,33 @@
+//! Converts a text dictionary, one word per line like `kata-dasar.txt`,
+//! into the binary format opened by `MmapDictionary`.
+//!
+//! Usage: `cargo run --example convert_dictionary -- <text file> <binary file>`
+
+use std::fs::File;
+use std::process::ExitCode;
+use rustrawi::dictionary::Dictionary;
+use rustrawi::dictionary::dictionary_format::DictionaryFormat;
+
+fn convert(text_filename: &str, binary_filename: &str) -> rustrawi::Result<usize> {
+    let dictionary = Dictionary::try_from_file(text_filename)?;
+    dictionary.write_to(File::create(binary_filename)?, DictionaryFormat::Binary)?;
+    Ok(dictionary.len())
+}
+
+fn main() -> ExitCode {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+    let [text_filename, binary_filename] = arguments.as_slice() else {
+        eprintln!("Usage: convert_dictionary <text file> <binary file>");
+        return ExitCode::FAILURE;
+    };
+    match convert(text_filename, binary_filename) {
+        Ok(word_count) => {
+            println!("Wrote {} words to {}", word_count, binary_filename);
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        },
+    }
+}
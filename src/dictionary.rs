@@ -2,10 +2,25 @@
 //!
 //! Contains the implementation of word dictionary and its occurrences
 
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::io;
 use std::fs::File;
-use std::io::BufRead;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use crate::dictionary::binary_table::BinaryTable;
+use crate::dictionary::dictionary_format::DictionaryFormat;
+use crate::error::{Error, Result};
+use crate::lexicon::Lexicon;
+
+mod binary_table;
+pub mod dictionary_format;
+#[cfg(feature = "fst")]
+pub mod fst_dictionary;
+#[cfg(feature = "mmap")]
+pub mod mmap_dictionary;
+
+/// Root words of the default dictionary, bundled into the crate
+#[cfg(feature = "bundled-dictionary")]
+pub(crate) const BUNDLED_WORDS: &str = include_str!("data/kata-dasar.txt");
 
 /// A dictionary structure to track word occurrences
 pub struct Dictionary {
@@ -53,11 +68,132 @@ impl Dictionary {
         dictionary
     }
 
-    /// Read line of string from a given filename
-    fn read_lines_from_file<P> (filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-    where P: AsRef<std::path::Path>, {
-        let file = File::open(filename)?;
-        Ok(io::BufReader::new(file).lines())
+    /// Initialize the dictionary from a collection of words along with their frequency
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// let dictionary = Dictionary::from_frequency_list(vec![("uang", 120), ("beruang", 3)]);
+    /// assert_eq!(dictionary.get_frequency("uang"), 120);
+    /// ```
+    pub fn from_frequency_list(word_list: Vec<(&str, usize)>) -> Self {
+        let mut dictionary = Dictionary::new();
+        for (word, frequency) in word_list {
+            dictionary.add_with_frequency(word.to_string(), frequency);
+        }
+        dictionary
+    }
+
+    /// Initialize the dictionary with the bundled root word list
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// let dictionary = Dictionary::bundled();
+    /// assert_eq!(dictionary.len(), 29932);
+    /// ```
+    #[cfg(feature = "bundled-dictionary")]
+    pub fn bundled() -> Self {
+        let mut dictionary = Dictionary::new();
+        for word in BUNDLED_WORDS.lines() {
+            dictionary.add(word.to_string());
+        }
+        dictionary
+    }
+
+    /// Calls `parse_line` with the line number and the content of each non-blank line.
+    ///
+    /// Returns an error on the first line that is not valid UTF-8, or contains a control character.
+    pub(crate) fn parse_lines(mut reader: impl BufRead, mut parse_line: impl FnMut(usize, &str) -> Result<()>) -> Result<()> {
+        let mut buffer = vec![];
+        let mut line_number = 0;
+        loop {
+            buffer.clear();
+            if reader.read_until(b'\n', &mut buffer)? == 0 {
+                return Ok(());
+            }
+            line_number += 1;
+
+            let invalid_line = || Error::Parse { line_number, line: String::from_utf8_lossy(&buffer).trim_end().to_string() };
+            let line = std::str::from_utf8(&buffer).map_err(|_| invalid_line())?.trim();
+            if line.chars().any(|c| c.is_control() && !c.is_whitespace()) {
+                return Err(invalid_line());
+            }
+            if !line.is_empty() {
+                parse_line(line_number, line)?;
+            }
+        }
+    }
+
+    /// Initialize dictionary from a buffered reader, one word per line,
+    /// or returns an error if it cannot be read or contains an invalid line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use rustrawi::dictionary::Dictionary;
+    /// let dictionary = Dictionary::try_from_buf_reader(Cursor::new("burung\nkucing\n")).unwrap();
+    /// assert_eq!(dictionary.len(), 2);
+    /// ```
+    pub fn try_from_buf_reader(reader: impl BufRead) -> Result<Self> {
+        let mut dictionary = Dictionary::new();
+        Dictionary::parse_lines(reader, |_, line| {
+            dictionary.add(line.to_string());
+            Ok(())
+        })?;
+        Ok(dictionary)
+    }
+
+    /// Initialize dictionary from a reader, one word per line,
+    /// or returns an error if it cannot be read or contains an invalid line.
+    pub fn try_from_reader(reader: impl Read) -> Result<Self> {
+        Dictionary::try_from_buf_reader(BufReader::new(reader))
+    }
+
+    /// Initialize dictionary from bytes, one word per line,
+    /// or returns an error if they contain an invalid line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// let dictionary = Dictionary::try_from_bytes(b"burung\r\nkucing").unwrap();
+    /// assert_eq!(dictionary.contains("kucing"), true);
+    /// assert_eq!(Dictionary::try_from_bytes(b"burung\n\xff").is_err(), true);
+    /// ```
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        Dictionary::try_from_buf_reader(bytes)
+    }
+
+    /// Initialize dictionary from a gzip compressed text file,
+    /// or returns an error if the file cannot be read or contains an invalid line.
+    #[cfg(feature = "gzip")]
+    pub fn try_from_gzip_file(filename: &str) -> Result<Self> {
+        Dictionary::try_from_gzip_reader(File::open(filename)?)
+    }
+
+    /// Initialize dictionary from a gzip compressed reader,
+    /// or returns an error if it cannot be read or contains an invalid line.
+    #[cfg(feature = "gzip")]
+    pub fn try_from_gzip_reader(reader: impl Read) -> Result<Self> {
+        Dictionary::try_from_reader(flate2::read::GzDecoder::new(reader))
+    }
+
+    /// Initialize dictionary from a zstd compressed text file,
+    /// or returns an error if the file cannot be read or contains an invalid line.
+    #[cfg(feature = "zstd")]
+    pub fn try_from_zstd_file(filename: &str) -> Result<Self> {
+        Dictionary::try_from_zstd_reader(File::open(filename)?)
+    }
+
+    /// Initialize dictionary from a zstd compressed reader,
+    /// or returns an error if it cannot be read or contains an invalid line.
+    #[cfg(feature = "zstd")]
+    pub fn try_from_zstd_reader(reader: impl Read) -> Result<Self> {
+        Dictionary::try_from_reader(zstd::stream::read::Decoder::new(reader)?)
     }
 
     /// Initialize dictionary from a text file
@@ -69,19 +205,156 @@ impl Dictionary {
     /// let dictionary = Dictionary::from_file("tests/example_word_list");
     /// assert_eq!(dictionary.len(), 3);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file cannot be read, see `try_from_file` for the fallible version.
     pub fn from_file(filename: &str) -> Self {
+        match Dictionary::try_from_file(filename) {
+            Ok(dictionary) => dictionary,
+            Err(e) => panic!("{}", e)
+        }
+    }
+
+    /// Initialize dictionary from a text file, one word per line,
+    /// or returns an error if the file cannot be read or contains an invalid line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// let dictionary = Dictionary::try_from_file("tests/example_word_list").unwrap();
+    /// assert_eq!(dictionary.len(), 3);
+    /// assert_eq!(Dictionary::try_from_file("tests/invalid_file").is_err(), true);
+    /// ```
+    pub fn try_from_file(filename: &str) -> Result<Self> {
+        Dictionary::try_from_reader(File::open(filename)?)
+    }
+
+    /// Initialize dictionary from a frequency-annotated text file
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file cannot be read or parsed, see `try_from_frequency_file` for the fallible version.
+    pub fn from_frequency_file(filename: &str) -> Self {
+        match Dictionary::try_from_frequency_file(filename) {
+            Ok(dictionary) => dictionary,
+            Err(e) => panic!("{}", e)
+        }
+    }
+
+    /// Initialize dictionary from a frequency-annotated text file,
+    /// or returns an error if the file cannot be read or parsed.
+    ///
+    /// Each line contains a word, optionally followed by whitespace and its frequency, as written by `DictionaryFormat::Tsv`.
+    /// A word without frequency is counted once, and blank lines are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// let dictionary = Dictionary::try_from_frequency_file("tests/example_frequency_list").unwrap();
+    /// assert_eq!(dictionary.get_frequency("kucing"), 25);
+    /// ```
+    pub fn try_from_frequency_file(filename: &str) -> Result<Self> {
+        Dictionary::try_from_frequency_reader(BufReader::new(File::open(filename)?))
+    }
+
+    /// Same as `try_from_frequency_file`, from a buffered reader
+    pub fn try_from_frequency_reader(reader: impl BufRead) -> Result<Self> {
         let mut dictionary = Dictionary::new();
-        match Dictionary::read_lines_from_file(&filename) {
-            Ok(lines) => {
-                for line in lines {
-                    if let Ok(value) = line {
-                        dictionary.add(value);
-                    }
+        Dictionary::parse_lines(reader, |line_number, line| {
+            let (word, frequency) = match line.rsplit_once(char::is_whitespace) {
+                Some((word, frequency)) => {
+                    let frequency = frequency.parse::<usize>()
+                        .map_err(|_| Error::Parse { line_number, line: line.to_string() })?;
+                    (word, frequency)
+                },
+                None => (line, 1),
+            };
+            dictionary.add_with_frequency(word.to_string(), frequency);
+            Ok(())
+        })?;
+        Ok(dictionary)
+    }
+
+    /// Initialize dictionary from a reader in the given format,
+    /// or returns an error if it cannot be read or parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::dictionary::dictionary_format::DictionaryFormat;
+    /// let dictionary = Dictionary::read_from("uang\t120\nberuang\t3\n".as_bytes(), DictionaryFormat::Tsv).unwrap();
+    /// assert_eq!(dictionary.get_frequency("uang"), 120);
+    /// ```
+    pub fn read_from(mut reader: impl Read, format: DictionaryFormat) -> Result<Self> {
+        match format {
+            DictionaryFormat::PlainText => Dictionary::try_from_reader(reader),
+            DictionaryFormat::Tsv => Dictionary::try_from_frequency_reader(BufReader::new(reader)),
+            #[cfg(feature = "serde")]
+            DictionaryFormat::Json => {
+                let words: HashMap<String, usize> = serde_json::from_reader(BufReader::new(reader))?;
+                let mut dictionary = Dictionary::new();
+                for (word, frequency) in words {
+                    dictionary.add_with_frequency(word, frequency);
                 }
+                Ok(dictionary)
+            },
+            DictionaryFormat::Binary => {
+                let mut bytes = vec![];
+                reader.read_to_end(&mut bytes)?;
+                let table = BinaryTable::try_from(&bytes)?;
+                let mut dictionary = Dictionary::new();
+                for index in 0..table.len() {
+                    let word = String::from_utf8(table.word(index).to_vec())
+                        .map_err(|_| Error::InvalidBinary("invalid UTF-8 word"))?;
+                    dictionary.add_with_frequency(word, table.frequency(index));
+                }
+                Ok(dictionary)
             },
-            Err(e) => panic!("{}", e)
         }
-        dictionary
+    }
+
+    /// Writes the dictionary in the given format, sorted by word,
+    /// or returns an error if the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::dictionary::dictionary_format::DictionaryFormat;
+    /// let dictionary = Dictionary::from_frequency_list(vec![("uang", 120), ("beruang", 3)]);
+    /// let mut output = vec![];
+    /// dictionary.write_to(&mut output, DictionaryFormat::Tsv).unwrap();
+    /// assert_eq!(String::from_utf8(output).unwrap(), "beruang\t3\nuang\t120\n");
+    /// ```
+    pub fn write_to(&self, writer: impl Write, format: DictionaryFormat) -> Result<()> {
+        let mut writer = BufWriter::new(writer);
+        let mut words: Vec<(&String, &usize)> = self.words.iter().collect();
+        words.sort_unstable();
+        match format {
+            DictionaryFormat::PlainText => {
+                for (word, _) in words {
+                    writeln!(writer, "{}", word)?;
+                }
+            },
+            DictionaryFormat::Tsv => {
+                for (word, frequency) in words {
+                    writeln!(writer, "{}\t{}", word, frequency)?;
+                }
+            },
+            #[cfg(feature = "serde")]
+            DictionaryFormat::Json => {
+                let words: std::collections::BTreeMap<&String, &usize> = words.into_iter().collect();
+                serde_json::to_writer_pretty(&mut writer, &words)?;
+                writeln!(writer)?;
+            },
+            DictionaryFormat::Binary => binary_table::write(&mut writer, &words)?,
+        }
+        writer.flush()?;
+        Ok(())
     }
 
     /// Add a word to the dictionary (or update its occurrences)
@@ -96,12 +369,54 @@ impl Dictionary {
     /// ```
     pub fn add(&mut self, word: String) {
         let word = word.trim().to_lowercase();
-        if word == "" {
+        if word.is_empty() {
             return;
         }
         *self.words.entry(word.to_string()).or_insert(0_usize) += 1_usize;
     }
 
+    /// Add a word to the dictionary with the given frequency (or add the frequency to its occurrences)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// let mut dictionary = Dictionary::new();
+    /// dictionary.add_with_frequency(String::from("burung"), 10);
+    /// dictionary.add(String::from("burung"));
+    /// assert_eq!(dictionary.get_frequency("burung"), 11);
+    /// ```
+    pub fn add_with_frequency(&mut self, word: String, frequency: usize) {
+        let word = word.trim().to_lowercase();
+        if word.is_empty() {
+            return;
+        }
+        *self.words.entry(word).or_insert(0_usize) += frequency;
+    }
+
+    /// Returns the occurrences of the given word, or 0 if the dictionary does not contain it
+    pub fn get_frequency(&self, word: &str) -> usize {
+        self.words.get(word.to_lowercase().as_str()).copied().unwrap_or(0)
+    }
+
+    /// Add a word to the dictionary, or returns an error if the word is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// let mut dictionary = Dictionary::new();
+    /// assert_eq!(dictionary.try_add(String::from("burung")).is_ok(), true);
+    /// assert_eq!(dictionary.try_add(String::from(" ")).is_err(), true);
+    /// ```
+    pub fn try_add(&mut self, word: String) -> Result<()> {
+        if word.trim().is_empty() {
+            return Err(Error::InvalidWord(word));
+        }
+        self.add(word);
+        Ok(())
+    }
+
     /// Add list of word to the dictionary
     pub fn add_from_list(&mut self, words: Vec<&str>) {
         for word in words {
@@ -114,19 +429,45 @@ impl Dictionary {
     /// Returns None if the dictionary did not contains the word
     pub fn remove(&mut self, word: String) -> Option<String>{
         let word = word.trim();
-        if word == "" {
+        if word.is_empty() {
             return None
         }
-        match self.words.remove(word) {
-            Some(_occurrence) => Some(word.to_string()),
-            None => None
-        }
+        self.words.remove(word).map(|_occurrence| word.to_string())
     }
 
     /// Returns the length of the word dictionary
     pub fn len(&self) -> usize {
         self.words.len()
     }
+
+    /// Returns true if the dictionary contains no word
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl Lexicon for Dictionary {
+    fn contains(&self, word: &str) -> bool {
+        Dictionary::contains(self, word)
+    }
+
+    fn frequency(&self, word: &str) -> Option<usize> {
+        self.words.get(word.to_lowercase().as_str()).copied()
+    }
+
+    fn words(&self) -> Option<Box<dyn Iterator<Item = Cow<'_, str>> + '_>> {
+        Some(Box::new(self.words.keys().map(|word| Cow::Borrowed(word.as_str()))))
+    }
+
+    fn len(&self) -> usize {
+        Dictionary::len(self)
+    }
+}
+
+impl Default for Dictionary {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
@@ -165,6 +506,18 @@ mod dictionary_from_test {
     fn should_panic_if_instantiating_from_invalid_file() {
         let _dictionary = Dictionary::from_file("tests/invalid_file");
     }
+
+    #[test]
+    fn should_try_to_instantiate_from_file() {
+        let dictionary = Dictionary::try_from_file("tests/example_word_list").unwrap();
+        assert_eq!(dictionary.len(), 3);
+    }
+
+    #[test]
+    fn should_return_error_if_instantiating_from_invalid_file() {
+        let result = Dictionary::try_from_file("tests/invalid_file");
+        assert_eq!(matches!(result, Err(Error::Io(_))), true);
+    }
 }
 
 #[cfg(test)]
@@ -224,6 +577,21 @@ mod dictionary_add_test {
         assert_eq!(dictionary.words.len(), 1);
     }
 
+    #[test]
+    fn should_try_to_add_word() {
+        let mut dictionary = Dictionary::new();
+        assert_eq!(dictionary.try_add(String::from(" burung ")).is_ok(), true);
+        assert_eq!(dictionary.contains("burung"), true);
+    }
+
+    #[test]
+    fn should_return_error_on_adding_empty_word() {
+        let mut dictionary = Dictionary::new();
+        assert_eq!(matches!(dictionary.try_add(String::from("")), Err(Error::InvalidWord(_))), true);
+        assert_eq!(matches!(dictionary.try_add(String::from(" ")), Err(Error::InvalidWord(_))), true);
+        assert_eq!(dictionary.len(), 0);
+    }
+
     #[test]
     fn should_add_from_list() {
         let mut dictionary = Dictionary::new();
@@ -236,6 +604,207 @@ mod dictionary_add_test {
     }
 }
 
+#[cfg(test)]
+mod dictionary_frequency_test {
+    use super::*;
+
+    #[test]
+    fn should_count_added_words() {
+        let mut dictionary = Dictionary::new();
+        assert_eq!(dictionary.get_frequency("burung"), 0);
+        dictionary.add(String::from("burung"));
+        dictionary.add(String::from("Burung"));
+        assert_eq!(dictionary.get_frequency("burung"), 2);
+    }
+
+    #[test]
+    fn should_add_with_frequency() {
+        let mut dictionary = Dictionary::new();
+        dictionary.add_with_frequency(String::from(" Burung "), 10);
+        dictionary.add_with_frequency(String::from(""), 10);
+        assert_eq!(dictionary.get_frequency("BURUNG"), 10);
+        assert_eq!(dictionary.len(), 1);
+    }
+
+    #[test]
+    fn should_initialize_from_frequency_list() {
+        let dictionary = Dictionary::from_frequency_list(vec![("uang", 120), ("beruang", 3), ("uang", 1)]);
+        assert_eq!(dictionary.len(), 2);
+        assert_eq!(dictionary.get_frequency("uang"), 121);
+        assert_eq!(dictionary.get_frequency("beruang"), 3);
+    }
+
+    #[test]
+    fn should_initialize_from_frequency_file() {
+        let dictionary = Dictionary::try_from_frequency_file("tests/example_frequency_list").unwrap();
+        assert_eq!(dictionary.len(), 3);
+        assert_eq!(dictionary.get_frequency("kucing"), 25);
+        assert_eq!(dictionary.get_frequency("ayam"), 7);
+        assert_eq!(dictionary.get_frequency("burung"), 1);
+    }
+
+    #[test]
+    fn should_return_error_on_invalid_frequency_file() {
+        assert_eq!(matches!(Dictionary::try_from_frequency_file("tests/invalid_file"), Err(Error::Io(_))), true);
+    }
+
+    #[test]
+    fn should_return_line_number_on_invalid_frequency() {
+        let result = Dictionary::try_from_frequency_file("tests/invalid_frequency_list");
+        assert_eq!(matches!(result, Err(Error::Parse { line_number: 2, .. })), true);
+    }
+}
+
+#[cfg(test)]
+mod dictionary_reader_test {
+    use std::io::Cursor;
+    use super::*;
+
+    #[test]
+    fn should_initialize_from_reader() {
+        let dictionary = Dictionary::try_from_reader(Cursor::new("burung\n\n  kucing \nayam")).unwrap();
+        assert_eq!(dictionary.len(), 3);
+        assert_eq!(dictionary.contains("kucing"), true);
+    }
+
+    #[test]
+    fn should_initialize_from_bytes() {
+        let dictionary = Dictionary::try_from_bytes("burung\r\nkafé\r\n".as_bytes()).unwrap();
+        assert_eq!(dictionary.len(), 2);
+        assert_eq!(dictionary.contains("kafé"), true);
+    }
+
+    #[test]
+    fn should_return_line_number_of_invalid_utf8() {
+        let result = Dictionary::try_from_bytes(b"burung\nkucing\n\xffayam\n");
+        match result {
+            Err(Error::Parse { line_number, line }) => {
+                assert_eq!(line_number, 3);
+                assert_eq!(line, "\u{fffd}ayam");
+            },
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn should_return_line_number_of_control_character() {
+        let result = Dictionary::try_from_bytes(b"burung\nkuc\x00ing\n");
+        assert_eq!(matches!(result, Err(Error::Parse { line_number: 2, .. })), true);
+    }
+
+    #[test]
+    fn should_initialize_frequency_list_from_reader() {
+        let dictionary = Dictionary::try_from_frequency_reader(Cursor::new("uang\t120\nberuang 3\n")).unwrap();
+        assert_eq!(dictionary.get_frequency("uang"), 120);
+        assert_eq!(dictionary.get_frequency("beruang"), 3);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn should_initialize_from_gzip_reader() {
+        use std::io::Write;
+        let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(b"burung\nkucing\n").unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let dictionary = Dictionary::try_from_gzip_reader(compressed.as_slice()).unwrap();
+        assert_eq!(dictionary.len(), 2);
+        assert_eq!(matches!(Dictionary::try_from_gzip_reader(&b"burung"[..]), Err(Error::Io(_))), true);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn should_initialize_from_zstd_reader() {
+        let compressed = zstd::encode_all(&b"burung\nkucing\n"[..], 0).unwrap();
+
+        let dictionary = Dictionary::try_from_zstd_reader(compressed.as_slice()).unwrap();
+        assert_eq!(dictionary.len(), 2);
+        assert_eq!(matches!(Dictionary::try_from_zstd_reader(&b"burung"[..]), Err(Error::Io(_))), true);
+    }
+}
+
+#[cfg(test)]
+mod dictionary_format_test {
+    use super::*;
+
+    fn write(dictionary: &Dictionary, format: DictionaryFormat) -> String {
+        let mut output = vec![];
+        dictionary.write_to(&mut output, format).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    fn dictionary() -> Dictionary {
+        Dictionary::from_frequency_list(vec![("uang", 120), ("kucing", 1), ("beruang", 3)])
+    }
+
+    #[test]
+    fn should_write_sorted_plain_text() {
+        assert_eq!(write(&dictionary(), DictionaryFormat::PlainText), "beruang\nkucing\nuang\n");
+        assert_eq!(write(&Dictionary::new(), DictionaryFormat::PlainText), "");
+    }
+
+    #[test]
+    fn should_write_sorted_tsv() {
+        assert_eq!(write(&dictionary(), DictionaryFormat::Tsv), "beruang\t3\nkucing\t1\nuang\t120\n");
+    }
+
+    #[test]
+    fn should_round_trip() {
+        #[cfg_attr(not(feature = "serde"), allow(unused_mut))]
+        let mut formats = vec![DictionaryFormat::PlainText, DictionaryFormat::Tsv];
+        #[cfg(feature = "serde")]
+        formats.push(DictionaryFormat::Json);
+
+        let dictionary = dictionary();
+        for format in formats {
+            let output = write(&dictionary, format);
+            let loaded = Dictionary::read_from(output.as_bytes(), format).unwrap();
+            assert_eq!(loaded.len(), 3);
+            assert_eq!(write(&loaded, format), output);
+        }
+    }
+
+    #[test]
+    fn should_round_trip_binary() {
+        let mut output = vec![];
+        dictionary().write_to(&mut output, DictionaryFormat::Binary).unwrap();
+        assert_eq!(&output[0..8], b"RUSTRAWI");
+
+        let loaded = Dictionary::read_from(output.as_slice(), DictionaryFormat::Binary).unwrap();
+        assert_eq!(write(&loaded, DictionaryFormat::Tsv), "beruang\t3\nkucing\t1\nuang\t120\n");
+        assert_eq!(matches!(Dictionary::read_from(&b"uang\n"[..], DictionaryFormat::Binary), Err(Error::InvalidBinary(_))), true);
+    }
+
+    #[test]
+    fn should_round_trip_word_with_whitespace() {
+        let dictionary = Dictionary::from_frequency_list(vec![("oper \"v,\"", 2)]);
+        let loaded = Dictionary::read_from(write(&dictionary, DictionaryFormat::Tsv).as_bytes(), DictionaryFormat::Tsv).unwrap();
+        assert_eq!(loaded.get_frequency("oper \"v,\""), 2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_write_sorted_json() {
+        assert_eq!(write(&dictionary(), DictionaryFormat::Json), "{\n  \"beruang\": 3,\n  \"kucing\": 1,\n  \"uang\": 120\n}\n");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_return_error_on_invalid_json() {
+        assert_eq!(matches!(Dictionary::read_from("[\"uang\"]".as_bytes(), DictionaryFormat::Json), Err(Error::Json(_))), true);
+    }
+
+    #[cfg(feature = "bundled-dictionary")]
+    #[test]
+    fn should_round_trip_bundled_dictionary() {
+        let dictionary = Dictionary::bundled();
+        let output = write(&dictionary, DictionaryFormat::Tsv);
+        let loaded = Dictionary::read_from(output.as_bytes(), DictionaryFormat::Tsv).unwrap();
+        assert_eq!(loaded.len(), dictionary.len());
+        assert_eq!(write(&loaded, DictionaryFormat::Tsv), output);
+    }
+}
+
 #[cfg(test)]
 mod dictionary_remove_test {
     use super::*;
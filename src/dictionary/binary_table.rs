@@ -0,0 +1,170 @@
+//! binary_table.rs
+//!
+//! Contains the binary dictionary format, written by `Dictionary::write_to` with `DictionaryFormat::Binary`
+//! and looked up in place by `MmapDictionary`.
+//!
+//! Every number is little-endian:
+//!
+//! | Size          | Content                                              |
+//! |---------------|------------------------------------------------------|
+//! | 8             | Magic bytes `RUSTRAWI`                               |
+//! | 4             | Format version, currently 1                          |
+//! | 4             | Number of words `n`                                  |
+//! | 4 × (n + 1)   | Offset of each word in the word data, then its size  |
+//! | 8 × n         | Occurrences of each word                             |
+//! | any           | Word data, the lowercase words sorted bytewise       |
+
+use std::io::{self, Write};
+use crate::error::{Error, Result};
+
+const MAGIC: &[u8; 8] = b"RUSTRAWI";
+const VERSION: u32 = 1;
+const HEADER_SIZE: usize = 16;
+
+/// Writes the given words, sorted bytewise, and their occurrences
+pub(crate) fn write(mut writer: impl Write, words: &[(&String, &usize)]) -> io::Result<()> {
+    let too_large = || io::Error::new(io::ErrorKind::InvalidInput, "dictionary is too large for the binary format");
+    let word_count = u32::try_from(words.len()).map_err(|_| too_large())?;
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    writer.write_all(&word_count.to_le_bytes())?;
+
+    let mut offset = 0_u32;
+    writer.write_all(&offset.to_le_bytes())?;
+    for (word, _) in words {
+        offset = u32::try_from(word.len()).ok()
+            .and_then(|length| offset.checked_add(length))
+            .ok_or_else(too_large)?;
+        writer.write_all(&offset.to_le_bytes())?;
+    }
+    for (_, frequency) in words {
+        writer.write_all(&(**frequency as u64).to_le_bytes())?;
+    }
+    for (word, _) in words {
+        writer.write_all(word.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Read-only view of a binary dictionary, looked up without copying the words
+#[derive(Clone, Copy)]
+pub(crate) struct BinaryTable<'a> {
+    offsets: &'a [u8],
+    frequencies: &'a [u8],
+    data: &'a [u8],
+    len: usize,
+}
+
+impl<'a> BinaryTable<'a> {
+    /// Checks the header and the size of the tables, without reading the words
+    pub(crate) fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.len() < HEADER_SIZE || &bytes[0..8] != MAGIC {
+            return Err(Error::InvalidBinary("missing header"));
+        }
+        if read_u32(&bytes[8..12]) != VERSION {
+            return Err(Error::InvalidBinary("unsupported version"));
+        }
+        let len = read_u32(&bytes[12..16]) as usize;
+
+        let frequencies_start = len.checked_add(1).and_then(|count| count.checked_mul(4)).and_then(|size| size.checked_add(HEADER_SIZE));
+        let data_start = frequencies_start.and_then(|start| len.checked_mul(8).and_then(|size| size.checked_add(start)));
+        let (frequencies_start, data_start) = match (frequencies_start, data_start) {
+            (Some(frequencies_start), Some(data_start)) if data_start <= bytes.len() => (frequencies_start, data_start),
+            _ => return Err(Error::InvalidBinary("truncated tables")),
+        };
+
+        let table = Self {
+            offsets: &bytes[HEADER_SIZE..frequencies_start],
+            frequencies: &bytes[frequencies_start..data_start],
+            data: &bytes[data_start..],
+            len,
+        };
+        if table.offset(len) != table.data.len() {
+            return Err(Error::InvalidBinary("truncated word data"));
+        }
+        Ok(table)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    fn offset(&self, index: usize) -> usize {
+        read_u32(&self.offsets[index * 4..index * 4 + 4]) as usize
+    }
+
+    /// Returns the word at the given index, or an empty word if its offsets are corrupted
+    pub(crate) fn word(&self, index: usize) -> &'a [u8] {
+        self.data.get(self.offset(index)..self.offset(index + 1)).unwrap_or_default()
+    }
+
+    pub(crate) fn frequency(&self, index: usize) -> usize {
+        let bytes = &self.frequencies[index * 8..index * 8 + 8];
+        u64::from_le_bytes(bytes.try_into().unwrap()) as usize
+    }
+
+    /// Returns the index of the given word, by binary search
+    #[cfg_attr(not(feature = "mmap"), allow(dead_code))]
+    pub(crate) fn find(&self, word: &str) -> Option<usize> {
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.word(middle).cmp(word.as_bytes()) {
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Equal => return Some(middle),
+            }
+        }
+        None
+    }
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes.try_into().unwrap())
+}
+
+#[cfg(test)]
+mod binary_table_test {
+    use super::*;
+
+    fn binary(words: Vec<(&str, usize)>) -> Vec<u8> {
+        let words: Vec<(String, usize)> = words.into_iter().map(|(word, frequency)| (word.to_string(), frequency)).collect();
+        let words: Vec<(&String, &usize)> = words.iter().map(|(word, frequency)| (word, frequency)).collect();
+        let mut output = vec![];
+        write(&mut output, &words).unwrap();
+        output
+    }
+
+    #[test]
+    fn should_find_words() {
+        let bytes = binary(vec![("beruang", 3), ("kucing", 1), ("uang", 120)]);
+        let table = BinaryTable::try_from(&bytes).unwrap();
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.find("uang").map(|index| table.frequency(index)), Some(120));
+        assert_eq!(table.find("beruang").map(|index| table.frequency(index)), Some(3));
+        assert_eq!(table.find("ayam"), None);
+        assert_eq!(table.find("zebra"), None);
+        assert_eq!(table.word(1), b"kucing");
+    }
+
+    #[test]
+    fn should_read_empty_table() {
+        let bytes = binary(vec![]);
+        let table = BinaryTable::try_from(&bytes).unwrap();
+        assert_eq!(table.len(), 0);
+        assert_eq!(table.find("uang"), None);
+    }
+
+    #[test]
+    fn should_reject_invalid_binary() {
+        let bytes = binary(vec![("uang", 120)]);
+        assert_eq!(matches!(BinaryTable::try_from(b"uang\n"), Err(Error::InvalidBinary("missing header"))), true);
+        assert_eq!(matches!(BinaryTable::try_from(&bytes[..bytes.len() - 1]), Err(Error::InvalidBinary("truncated word data"))), true);
+        assert_eq!(matches!(BinaryTable::try_from(&bytes[..20]), Err(Error::InvalidBinary("truncated tables"))), true);
+
+        let mut bytes = bytes;
+        bytes[8] = 2;
+        assert_eq!(matches!(BinaryTable::try_from(&bytes), Err(Error::InvalidBinary("unsupported version"))), true);
+    }
+}
@@ -0,0 +1,18 @@
+/// File format of a dictionary, used by `Dictionary::write_to` and `Dictionary::read_from`.
+///
+/// Words are always written in sorted order, so changes to a dictionary diff cleanly.
+/// New formats may be added, e.g. behind a feature, so a `match` needs a wildcard arm.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum DictionaryFormat {
+    /// One word per line, without the occurrences
+    #[default]
+    PlainText,
+    /// One word per line, followed by a tab and its occurrences, e.g. "uang\t120"
+    Tsv,
+    /// A JSON object mapping each word to its occurrences, e.g. `{"uang": 120}`
+    #[cfg(feature = "serde")]
+    Json,
+    /// Sorted binary tables, opened in place by `MmapDictionary` with the `mmap` feature
+    Binary,
+}
@@ -0,0 +1,236 @@
+use std::borrow::Cow;
+use std::io::BufRead;
+use fst::automaton::{Automaton, Str};
+use fst::{IntoStreamer, Map, MapBuilder, Streamer};
+use crate::dictionary::Dictionary;
+use crate::error::{Error, Result};
+use crate::lexicon::Lexicon;
+
+/// Compact immutable dictionary, backed by a finite-state transducer.
+///
+/// Words sharing prefixes and suffixes are stored once, so the bundled root words
+/// take a fraction of the memory of a `Dictionary`. Built once from a `Dictionary`,
+/// it can not be modified afterwards.
+/// A sorted word list can be streamed into it with `try_from_sorted_reader`, without building a `Dictionary`.
+///
+/// # Examples
+///
+/// ```
+/// use rustrawi::dictionary::Dictionary;
+/// use rustrawi::dictionary::fst_dictionary::FstDictionary;
+/// use rustrawi::stemmer::Stemmer;
+/// let dictionary = FstDictionary::from(&Dictionary::from_frequency_list(vec![("baca", 5), ("bacakan", 1)]));
+/// assert_eq!(dictionary.contains("baca"), true);
+/// assert_eq!(dictionary.get_frequency("baca"), 5);
+/// assert_eq!(dictionary.words_with_prefix("bac").len(), 2);
+///
+/// let stemmer = Stemmer::with_lexicon(dictionary);
+/// assert_eq!(stemmer.stem(String::from("membaca")), "baca");
+/// ```
+pub struct FstDictionary {
+    map: Map<Vec<u8>>,
+}
+
+impl FstDictionary {
+    /// Initialize the dictionary with the words and occurrences of the given dictionary
+    pub fn from(dictionary: &Dictionary) -> Self {
+        let mut words: Vec<(&String, &usize)> = dictionary.words.iter().collect();
+        words.sort_unstable();
+
+        let mut builder = MapBuilder::memory();
+        for (word, frequency) in words {
+            builder.insert(word, *frequency as u64).expect("words are sorted and unique");
+        }
+        Self {
+            map: builder.into_map(),
+        }
+    }
+
+    /// Initialize the dictionary with the bundled root word list
+    #[cfg(feature = "bundled-dictionary")]
+    pub fn bundled() -> Self {
+        Self::try_from_sorted_reader(crate::dictionary::BUNDLED_WORDS.as_bytes()).expect("bundled words are sorted")
+    }
+
+    /// Initialize the dictionary from a buffered reader, one word per line, sorted bytewise once lowercased,
+    /// e.g. `kata-dasar.txt`. The words are streamed into the transducer, so no `Dictionary` is built.
+    ///
+    /// A word repeated on consecutive lines counts one occurrence per line.
+    /// Returns an error if it cannot be read, or contains an invalid line or a line sorted before the previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::fst_dictionary::FstDictionary;
+    /// let dictionary = FstDictionary::try_from_sorted_reader("baca\nbaca\ntulis\n".as_bytes()).unwrap();
+    /// assert_eq!(dictionary.len(), 2);
+    /// assert_eq!(dictionary.get_frequency("baca"), 2);
+    /// assert_eq!(FstDictionary::try_from_sorted_reader("tulis\nbaca\n".as_bytes()).is_err(), true);
+    /// ```
+    pub fn try_from_sorted_reader(reader: impl BufRead) -> Result<Self> {
+        let mut builder = MapBuilder::memory();
+        let mut previous: Option<(String, u64)> = None;
+        Dictionary::parse_lines(reader, |line_number, line| {
+            let word = line.to_lowercase();
+            if let Some((previous_word, frequency)) = &mut previous {
+                if *previous_word == word {
+                    *frequency += 1;
+                    return Ok(());
+                }
+                if *previous_word > word {
+                    return Err(Error::Parse { line_number, line: line.to_string() });
+                }
+            }
+            if let Some((previous_word, frequency)) = previous.replace((word, 1)) {
+                builder.insert(previous_word, frequency).expect("words are sorted and unique");
+            }
+            Ok(())
+        })?;
+        if let Some((word, frequency)) = previous {
+            builder.insert(word, frequency).expect("words are sorted and unique");
+        }
+        Ok(Self {
+            map: builder.into_map(),
+        })
+    }
+
+    /// Initialize dictionary from a text file, one word per line,
+    /// or returns an error if the file cannot be read or contains an invalid line.
+    pub fn try_from_file(filename: &str) -> Result<Self> {
+        Ok(Self::from(&Dictionary::try_from_file(filename)?))
+    }
+
+    /// Checks whether the dictionary contains the given word
+    pub fn contains(&self, word: &str) -> bool {
+        self.map.contains_key(word.to_lowercase())
+    }
+
+    /// Returns the occurrences of the given word, or 0 if the dictionary does not contain it
+    pub fn get_frequency(&self, word: &str) -> usize {
+        self.map.get(word.to_lowercase()).map_or(0, |frequency| frequency as usize)
+    }
+
+    /// Returns every word starting with the given prefix, along with its occurrences, in sorted order
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<(String, usize)> {
+        let prefix = prefix.to_lowercase();
+        let mut stream = self.map.search(Str::new(&prefix).starts_with()).into_stream();
+        let mut words = vec![];
+        while let Some((word, frequency)) = stream.next() {
+            words.push((String::from_utf8_lossy(word).into_owned(), frequency as usize));
+        }
+        words
+    }
+
+    /// Returns dictionary length
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the dictionary is empty
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the number of bytes taken by the transducer
+    pub fn get_size_in_bytes(&self) -> usize {
+        self.map.as_fst().size()
+    }
+}
+
+impl Lexicon for FstDictionary {
+    fn contains(&self, word: &str) -> bool {
+        FstDictionary::contains(self, word)
+    }
+
+    fn frequency(&self, word: &str) -> Option<usize> {
+        self.map.get(word.to_lowercase()).map(|frequency| frequency as usize)
+    }
+
+    /// Streams the words from the transducer, in sorted order
+    fn words(&self) -> Option<Box<dyn Iterator<Item = Cow<'_, str>> + '_>> {
+        let mut stream = self.map.stream();
+        Some(Box::new(std::iter::from_fn(move || {
+            stream.next().map(|(word, _)| Cow::Owned(String::from_utf8_lossy(word).into_owned()))
+        })))
+    }
+
+    fn len(&self) -> usize {
+        FstDictionary::len(self)
+    }
+}
+
+#[cfg(test)]
+mod fst_dictionary_test {
+    use super::*;
+
+    fn dictionary() -> FstDictionary {
+        FstDictionary::from(&Dictionary::from_frequency_list(vec![("uang", 120), ("beruang", 3), ("ular", 1), ("ulang", 7)]))
+    }
+
+    #[test]
+    fn should_contain_words_of_dictionary() {
+        let dictionary = dictionary();
+        assert_eq!(dictionary.len(), 4);
+        assert_eq!(dictionary.contains("uang"), true);
+        assert_eq!(dictionary.contains("Beruang"), true);
+        assert_eq!(dictionary.contains("ber"), false);
+        assert_eq!(FstDictionary::from(&Dictionary::new()).is_empty(), true);
+    }
+
+    #[test]
+    fn should_return_frequency() {
+        let dictionary = dictionary();
+        assert_eq!(dictionary.get_frequency("uang"), 120);
+        assert_eq!(dictionary.get_frequency("kucing"), 0);
+        assert_eq!(Lexicon::frequency(&dictionary, "beruang"), Some(3));
+        assert_eq!(Lexicon::frequency(&dictionary, "kucing"), None);
+    }
+
+    #[test]
+    fn should_iterate_words_with_prefix() {
+        let dictionary = dictionary();
+        assert_eq!(dictionary.words_with_prefix("ul"), vec![(String::from("ulang"), 7), (String::from("ular"), 1)]);
+        assert_eq!(dictionary.words_with_prefix("").len(), 4);
+        assert_eq!(dictionary.words_with_prefix("x").len(), 0);
+        assert_eq!(Lexicon::words(&dictionary).unwrap().next(), Some(Cow::Borrowed("beruang")));
+        assert_eq!(Lexicon::words(&dictionary).unwrap().collect::<Vec<_>>(), vec!["beruang", "uang", "ulang", "ular"]);
+    }
+
+    #[test]
+    fn should_stream_sorted_reader() {
+        let dictionary = FstDictionary::try_from_sorted_reader("beruang\nUang\nuang\n\nular\n".as_bytes()).unwrap();
+        assert_eq!(dictionary.len(), 3);
+        assert_eq!(dictionary.get_frequency("uang"), 2);
+        assert_eq!(dictionary.contains("ular"), true);
+        assert_eq!(FstDictionary::try_from_sorted_reader("".as_bytes()).unwrap().is_empty(), true);
+    }
+
+    #[test]
+    fn should_return_error_on_unsorted_reader() {
+        let result = FstDictionary::try_from_sorted_reader("uang\nular\nberuang\n".as_bytes());
+        assert_eq!(matches!(result, Err(Error::Parse { line_number: 3, line }) if line == "beruang"), true);
+        assert_eq!(FstDictionary::try_from_sorted_reader("uang\n\x01\n".as_bytes()).is_err(), true);
+    }
+
+    #[test]
+    fn should_return_error_on_invalid_file() {
+        assert_eq!(FstDictionary::try_from_file("tests/invalid_file").is_err(), true);
+    }
+
+    #[cfg(feature = "bundled-dictionary")]
+    #[test]
+    fn should_be_smaller_than_word_list() {
+        let dictionary = FstDictionary::bundled();
+        assert_eq!(dictionary.len(), Dictionary::bundled().len());
+        assert_eq!(dictionary.get_size_in_bytes() < include_str!("../data/kata-dasar.txt").len(), true);
+    }
+
+    #[cfg(feature = "bundled-dictionary")]
+    #[test]
+    fn should_stem_like_dictionary() {
+        use crate::stemmer::Stemmer;
+        let text = String::from("Perekonomian Indonesia sedang dalam pertumbuhan yang membanggakan");
+        let expected = Stemmer::new().stem(text.clone());
+        assert_eq!(Stemmer::with_lexicon(FstDictionary::bundled()).stem(text), expected);
+    }
+}
@@ -0,0 +1,180 @@
+use std::borrow::Cow;
+use std::fs::File;
+use memmap2::Mmap;
+use crate::dictionary::binary_table::BinaryTable;
+use crate::error::Result;
+use crate::lexicon::Lexicon;
+
+/// Immutable dictionary, looked up in place from a memory-mapped binary file.
+///
+/// Opening the file only checks its header, so the startup cost does not grow with the number of words.
+/// The file is mapped into memory rather than copied, so it must not be modified while the dictionary is open,
+/// which is why opening it is `unsafe`.
+/// The binary file is written by `Dictionary::write_to` with `DictionaryFormat::Binary`,
+/// e.g. with `cargo run --example convert_dictionary -- src/data/kata-dasar.txt kata-dasar.bin`.
+///
+/// # Examples
+///
+/// ```
+/// use std::fs::File;
+/// use rustrawi::dictionary::Dictionary;
+/// use rustrawi::dictionary::dictionary_format::DictionaryFormat;
+/// use rustrawi::dictionary::mmap_dictionary::MmapDictionary;
+/// use rustrawi::stemmer::Stemmer;
+///
+/// let filename = std::env::temp_dir().join("rustrawi-mmap-dictionary-example.bin");
+/// let dictionary = Dictionary::from_frequency_list(vec![("baca", 5), ("tulis", 2)]);
+/// dictionary.write_to(File::create(&filename).unwrap(), DictionaryFormat::Binary).unwrap();
+///
+/// // SAFETY: the file is not modified while the dictionary is open
+/// let dictionary = unsafe { MmapDictionary::try_open(filename.to_str().unwrap()) }.unwrap();
+/// assert_eq!(dictionary.contains("baca"), true);
+/// assert_eq!(dictionary.get_frequency("tulis"), 2);
+///
+/// let stemmer = Stemmer::with_lexicon(dictionary);
+/// assert_eq!(stemmer.stem(String::from("membaca")), "baca");
+/// ```
+pub struct MmapDictionary {
+    mmap: Mmap,
+}
+
+impl MmapDictionary {
+    /// Opens a binary dictionary file.
+    ///
+    /// # Safety
+    ///
+    /// Same as `try_open`: the file must not be modified or truncated while the dictionary is open.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file cannot be read or is not a binary dictionary, see `try_open` for the fallible version.
+    pub unsafe fn open(filename: &str) -> Self {
+        // SAFETY: the caller upholds the same requirement as `try_open`
+        match unsafe { MmapDictionary::try_open(filename) } {
+            Ok(dictionary) => dictionary,
+            Err(e) => panic!("{}", e)
+        }
+    }
+
+    /// Opens a binary dictionary file,
+    /// or returns an error if the file cannot be read or is not a binary dictionary.
+    ///
+    /// # Safety
+    ///
+    /// The file is mapped into memory, not copied, so it must not be modified or truncated,
+    /// by this process or any other, while the dictionary is open.
+    /// Reading a truncated mapping raises SIGBUS, and a mapping written by someone else
+    /// breaks the immutability the dictionary relies on, which is undefined behaviour.
+    pub unsafe fn try_open(filename: &str) -> Result<Self> {
+        let file = File::open(filename)?;
+        // SAFETY: the caller guarantees that the file is neither modified nor truncated
+        // while it is mapped, so the mapped bytes stay valid and immutable for the lifetime of `mmap`.
+        let mmap = unsafe { Mmap::map(&file)? };
+        BinaryTable::try_from(&mmap)?;
+        Ok(Self { mmap })
+    }
+
+    fn table(&self) -> BinaryTable<'_> {
+        BinaryTable::try_from(&self.mmap).expect("header is checked on open")
+    }
+
+    /// Checks whether the dictionary contains the given word
+    pub fn contains(&self, word: &str) -> bool {
+        self.table().find(&word.to_lowercase()).is_some()
+    }
+
+    /// Returns the occurrences of the given word, or 0 if the dictionary does not contain it
+    pub fn get_frequency(&self, word: &str) -> usize {
+        Lexicon::frequency(self, word).unwrap_or(0)
+    }
+
+    /// Returns dictionary length
+    pub fn len(&self) -> usize {
+        self.table().len()
+    }
+
+    /// Returns true if the dictionary is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Lexicon for MmapDictionary {
+    fn contains(&self, word: &str) -> bool {
+        MmapDictionary::contains(self, word)
+    }
+
+    fn frequency(&self, word: &str) -> Option<usize> {
+        let table = self.table();
+        table.find(&word.to_lowercase()).map(|index| table.frequency(index))
+    }
+
+    fn words(&self) -> Option<Box<dyn Iterator<Item = Cow<'_, str>> + '_>> {
+        let table = self.table();
+        Some(Box::new((0..table.len()).map(move |index| String::from_utf8_lossy(table.word(index)))))
+    }
+
+    fn len(&self) -> usize {
+        MmapDictionary::len(self)
+    }
+}
+
+#[cfg(test)]
+mod mmap_dictionary_test {
+    use std::path::{Path, PathBuf};
+    use super::*;
+    use crate::dictionary::Dictionary;
+    use crate::dictionary::dictionary_format::DictionaryFormat;
+    use crate::error::Error;
+
+    /// Each test writes its own file, which is never modified once the dictionary is open
+    fn open(filename: &Path) -> MmapDictionary {
+        unsafe { MmapDictionary::open(filename.to_str().unwrap()) }
+    }
+
+    fn write(name: &str, dictionary: &Dictionary) -> PathBuf {
+        let filename = std::env::temp_dir().join(format!("rustrawi-{}-{}.bin", name, std::process::id()));
+        dictionary.write_to(File::create(&filename).unwrap(), DictionaryFormat::Binary).unwrap();
+        filename
+    }
+
+    #[test]
+    fn should_look_up_words() {
+        let filename = write("look-up", &Dictionary::from_frequency_list(vec![("uang", 120), ("beruang", 3), ("ular", 1)]));
+        let dictionary = open(&filename);
+        assert_eq!(dictionary.len(), 3);
+        assert_eq!(dictionary.contains("Uang"), true);
+        assert_eq!(dictionary.contains("ulang"), false);
+        assert_eq!(dictionary.get_frequency("beruang"), 3);
+        assert_eq!(Lexicon::frequency(&dictionary, "ulang"), None);
+
+        let words: Vec<Cow<str>> = Lexicon::words(&dictionary).unwrap().collect();
+        assert_eq!(words, vec!["beruang", "uang", "ular"]);
+    }
+
+    #[test]
+    fn should_open_empty_dictionary() {
+        let filename = write("empty", &Dictionary::new());
+        assert_eq!(open(&filename).is_empty(), true);
+    }
+
+    #[test]
+    fn should_return_error_on_text_file() {
+        let try_open = |filename| unsafe { MmapDictionary::try_open(filename) };
+        assert_eq!(matches!(try_open("tests/example_word_list"), Err(Error::InvalidBinary(_))), true);
+        assert_eq!(matches!(try_open("tests/invalid_file"), Err(Error::Io(_))), true);
+    }
+
+    #[cfg(feature = "bundled-dictionary")]
+    #[test]
+    fn should_stem_like_dictionary() {
+        use crate::stemmer::Stemmer;
+        let filename = write("bundled", &Dictionary::bundled());
+        let dictionary = open(&filename);
+        assert_eq!(dictionary.len(), Dictionary::bundled().len());
+
+        let text = String::from("Perekonomian Indonesia sedang dalam pertumbuhan yang membanggakan");
+        let expected = Stemmer::new().stem(text.clone());
+        assert_eq!(Stemmer::with_lexicon(dictionary).stem(text), expected);
+    }
+}
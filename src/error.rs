@@ -0,0 +1,137 @@
+//! error.rs
+//!
+//! Contains the error type returned by the fallible functions of this crate
+
+use std::fmt;
+use std::io;
+use crate::stemmer::context::visitor::VisitorType;
+
+/// Errors that can happen while building a dictionary or stemming a word.
+///
+/// New variants may be added, e.g. behind a feature, so a `match` needs a wildcard arm.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The given file cannot be read
+    Io(io::Error),
+    /// The given word is empty, or is not a single word
+    InvalidWord(String),
+    /// A line of a word list cannot be parsed, `line_number` starts from 1
+    Parse {
+        line_number: usize,
+        line: String,
+    },
+    /// A binary dictionary is truncated, or is not a binary dictionary
+    InvalidBinary(&'static str),
+    /// A JSON dictionary cannot be parsed
+    #[cfg(feature = "serde")]
+    Json(serde_json::Error),
+    /// The resulting word is requested before the stemming process is executed
+    ResultNotAvailable,
+    /// A visitor is registered in a phase other than the one of its declared `VisitorType`
+    MisplacedVisitor {
+        visitor_name: &'static str,
+        visitor_type: VisitorType,
+        phase: VisitorType,
+    },
+    /// A visitor is inserted or moved at an index out of bounds of its phase
+    InvalidVisitorIndex {
+        phase: VisitorType,
+        index: usize,
+    },
+}
+
+/// Result type of the fallible functions of this crate
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::InvalidWord(word) => write!(f, "Invalid word: {:?}", word),
+            Error::Parse { line_number, line } => write!(f, "Invalid line {}: {:?}", line_number, line),
+            Error::InvalidBinary(reason) => write!(f, "Invalid binary dictionary: {}", reason),
+            #[cfg(feature = "serde")]
+            Error::Json(e) => write!(f, "{}", e),
+            Error::ResultNotAvailable => write!(f, "Resulting word is being called before available"),
+            Error::MisplacedVisitor { visitor_name, visitor_type, phase } => {
+                write!(f, "{} is a {:?}, but is registered as {:?}", visitor_name, visitor_type, phase)
+            }
+            Error::InvalidVisitorIndex { phase, index } => write!(f, "Visitor index {} is out of bounds of {:?}", index, phase),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            #[cfg(feature = "serde")]
+            Error::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        if e.is_io() {
+            Error::Io(e.into())
+        } else {
+            Error::Json(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod error_test {
+    use super::*;
+
+    #[test]
+    fn should_display_io_error() {
+        let error = Error::from(io::Error::new(io::ErrorKind::NotFound, "No such file or directory"));
+        assert_eq!(error.to_string(), "No such file or directory");
+        assert_eq!(std::error::Error::source(&error).is_some(), true);
+    }
+
+    #[test]
+    fn should_display_invalid_word() {
+        let error = Error::InvalidWord(String::from("kucing ayam"));
+        assert_eq!(error.to_string(), "Invalid word: \"kucing ayam\"");
+    }
+
+    #[test]
+    fn should_display_parse_error() {
+        let error = Error::Parse { line_number: 2, line: String::from("kucing x") };
+        assert_eq!(error.to_string(), "Invalid line 2: \"kucing x\"");
+    }
+
+    #[test]
+    fn should_display_invalid_binary() {
+        let error = Error::InvalidBinary("missing header");
+        assert_eq!(error.to_string(), "Invalid binary dictionary: missing header");
+    }
+
+    #[test]
+    fn should_display_misplaced_visitor() {
+        let error = Error::MisplacedVisitor {
+            visitor_name: "RemovePlainPrefix",
+            visitor_type: VisitorType::PrefixVisitor,
+            phase: VisitorType::SuffixVisitor,
+        };
+        assert_eq!(error.to_string(), "RemovePlainPrefix is a PrefixVisitor, but is registered as SuffixVisitor");
+    }
+
+    #[test]
+    fn should_display_invalid_visitor_index() {
+        let error = Error::InvalidVisitorIndex { phase: VisitorType::PrefixVisitor, index: 3 };
+        assert_eq!(error.to_string(), "Visitor index 3 is out of bounds of PrefixVisitor");
+    }
+}
@@ -0,0 +1,174 @@
+//! lexicon.rs
+//!
+//! Contains the trait of the word stores looked up by the stemming process
+//! and the stop word removal
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Store of words, looked up by the stemming process and the stop word removal.
+///
+/// Implemented by `Dictionary`, by the compact `FstDictionary` with the `fst` feature,
+/// and by a `HashSet<String>` of lowercase words. A store shared behind an `Arc`
+/// or owned in a `Box` is a lexicon as well.
+///
+/// # Examples
+///
+/// ```
+/// use rustrawi::lexicon::Lexicon;
+/// use rustrawi::stemmer::Stemmer;
+///
+/// /// Accepts only the word "baca"
+/// struct OnlyBaca;
+///
+/// impl Lexicon for OnlyBaca {
+///     fn contains(&self, word: &str) -> bool {
+///         word == "baca"
+///     }
+///
+///     fn len(&self) -> usize {
+///         1
+///     }
+/// }
+///
+/// let stemmer = Stemmer::with_lexicon(OnlyBaca);
+/// assert_eq!(stemmer.stem(String::from("membacakan")), "baca");
+/// ```
+pub trait Lexicon: Send + Sync {
+    /// Checks whether the lexicon contains the given lowercase word
+    fn contains(&self, word: &str) -> bool;
+
+    /// Returns the occurrences of the given word,
+    /// or None if the lexicon does not contain it or does not keep track of occurrences
+    fn frequency(&self, _word: &str) -> Option<usize> {
+        None
+    }
+
+    /// Returns every word of the lexicon, in any order,
+    /// or None if the lexicon can not list its words, e.g. a lexicon backed by a database
+    fn words(&self) -> Option<Box<dyn Iterator<Item = Cow<'_, str>> + '_>> {
+        None
+    }
+
+    /// Returns the number of words in the lexicon
+    fn len(&self) -> usize;
+
+    /// Returns true if the lexicon has no word
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Lexicon for HashSet<String> {
+    fn contains(&self, word: &str) -> bool {
+        HashSet::contains(self, word)
+    }
+
+    fn words(&self) -> Option<Box<dyn Iterator<Item = Cow<'_, str>> + '_>> {
+        Some(Box::new(self.iter().map(|word| Cow::Borrowed(word.as_str()))))
+    }
+
+    fn len(&self) -> usize {
+        HashSet::len(self)
+    }
+}
+
+impl<L: Lexicon + ?Sized> Lexicon for Arc<L> {
+    fn contains(&self, word: &str) -> bool {
+        self.as_ref().contains(word)
+    }
+
+    fn frequency(&self, word: &str) -> Option<usize> {
+        self.as_ref().frequency(word)
+    }
+
+    fn words(&self) -> Option<Box<dyn Iterator<Item = Cow<'_, str>> + '_>> {
+        self.as_ref().words()
+    }
+
+    fn len(&self) -> usize {
+        self.as_ref().len()
+    }
+}
+
+impl<L: Lexicon + ?Sized> Lexicon for Box<L> {
+    fn contains(&self, word: &str) -> bool {
+        self.as_ref().contains(word)
+    }
+
+    fn frequency(&self, word: &str) -> Option<usize> {
+        self.as_ref().frequency(word)
+    }
+
+    fn words(&self) -> Option<Box<dyn Iterator<Item = Cow<'_, str>> + '_>> {
+        self.as_ref().words()
+    }
+
+    fn len(&self) -> usize {
+        self.as_ref().len()
+    }
+}
+
+#[cfg(test)]
+mod lexicon_test {
+    use super::*;
+    use crate::dictionary::Dictionary;
+    use crate::stemmer::Stemmer;
+    use crate::stop_word_remover::StopWordRemover;
+
+    fn sorted_words(lexicon: &dyn Lexicon) -> Option<Vec<String>> {
+        let mut words: Vec<String> = lexicon.words()?.map(Cow::into_owned).collect();
+        words.sort();
+        Some(words)
+    }
+
+    #[test]
+    fn should_list_words() {
+        let dictionary = Dictionary::from_list(vec!["kucing", "ayam"]);
+        assert_eq!(sorted_words(&dictionary), Some(vec![String::from("ayam"), String::from("kucing")]));
+
+        let words = HashSet::from([String::from("baca")]);
+        assert_eq!(sorted_words(&words), Some(vec![String::from("baca")]));
+    }
+
+    #[test]
+    fn should_not_list_words_by_default() {
+        struct AnyWord;
+
+        impl Lexicon for AnyWord {
+            fn contains(&self, _word: &str) -> bool {
+                true
+            }
+
+            fn len(&self) -> usize {
+                0
+            }
+        }
+
+        assert_eq!(AnyWord.words().is_none(), true);
+        assert_eq!(AnyWord.frequency("baca"), None);
+        assert_eq!(AnyWord.is_empty(), true);
+    }
+
+    #[test]
+    fn should_delegate_through_arc_and_box() {
+        let dictionary = Dictionary::from_frequency_list(vec![("baca", 3)]);
+        let lexicon: Box<dyn Lexicon> = Box::new(Arc::new(dictionary));
+        assert_eq!(lexicon.contains("baca"), true);
+        assert_eq!(lexicon.frequency("baca"), Some(3));
+        assert_eq!(lexicon.len(), 1);
+        assert_eq!(sorted_words(&lexicon), Some(vec![String::from("baca")]));
+    }
+
+    #[test]
+    fn should_share_word_set_between_stemmer_and_stop_word_remover() {
+        let words = Arc::new(HashSet::from([String::from("baca"), String::from("dan")]));
+        let stemmer = Stemmer::with_lexicon(Arc::clone(&words));
+        let stop_word_remover = StopWordRemover::with_lexicon(Arc::clone(&words));
+
+        assert_eq!(stemmer.stem(String::from("membaca dan menulis")), "baca dan menulis");
+        assert_eq!(stop_word_remover.remove(String::from("baca dan tulis")), "tulis");
+        assert_eq!(Arc::strong_count(&words), 3);
+    }
+}
@@ -1,3 +1,11 @@
+#![cfg_attr(test, allow(clippy::bool_assert_comparison))]
+
 pub mod dictionary;
+pub mod error;
+pub mod lexicon;
+pub mod normalizer;
 pub mod stop_word_remover;
 pub mod stemmer;
+pub mod tokenizer;
+
+pub use error::{Error, Result};
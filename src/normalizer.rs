@@ -0,0 +1,227 @@
+use std::ops::Range;
+use unicode_normalization::char::is_combining_mark;
+use crate::normalizer::alphabet_filter::AlphabetFilter;
+use crate::normalizer::apostrophe_policy::ApostrophePolicy;
+use crate::normalizer::case_policy::CasePolicy;
+
+pub mod alphabet_filter;
+pub mod apostrophe_policy;
+pub mod case_policy;
+pub mod dash_unification;
+pub mod digit_policy;
+pub mod strip_diacritics;
+pub mod unicode_folding;
+
+/// A single step of the text normalization, applied on each grapheme cluster
+pub trait NormalizationStep: Send + Sync {
+    /// Appends the normalized form of `c` to `output`, which can be empty or several characters
+    fn normalize_char(&self, c: char, output: &mut String);
+
+    /// Appends the normalized form of a cluster to `output`.
+    ///
+    /// A cluster is a character of the original text followed by its combining marks,
+    /// as normalized by the previous steps. Each of its characters is normalized on its own by default.
+    fn normalize_cluster(&self, cluster: &str, output: &mut String) {
+        for c in cluster.chars() {
+            self.normalize_char(c, output);
+        }
+    }
+}
+
+/// Normalizes the text before it is tokenized, by running each character, along with its combining marks,
+/// through every step in order.
+///
+/// The default preset splits words on apostrophes, lowercases every letter,
+/// and replaces every character other than `[a-z0-9 -]` with a whitespace, as the stemmer always did.
+/// Every other step is opt-in.
+///
+/// # Examples
+///
+/// ```
+/// use rustrawi::normalizer::Normalizer;
+/// use rustrawi::normalizer::apostrophe_policy::ApostrophePolicy;
+/// use rustrawi::normalizer::case_policy::CasePolicy;
+/// use rustrawi::normalizer::strip_diacritics::StripDiacritics;
+/// use rustrawi::normalizer::unicode_folding::UnicodeFolding;
+///
+/// let mut normalizer = Normalizer::empty();
+/// normalizer.add_step(Box::new(UnicodeFolding));
+/// normalizer.add_step(Box::new(StripDiacritics));
+/// normalizer.add_step(Box::new(ApostrophePolicy::Remove));
+/// normalizer.add_step(Box::new(CasePolicy::Lowercase));
+/// assert_eq!(normalizer.normalize("Kafé Ｑｕｒ'an"), "kafe quran");
+/// ```
+pub struct Normalizer {
+    steps: Vec<Box<dyn NormalizationStep>>,
+}
+
+/// Normalized text, along with the location of each of its characters in the original text
+pub struct NormalizedText {
+    text: String,
+    /// Byte range in the original text of the character producing each byte of the normalized text
+    origins: Vec<Range<usize>>,
+    original_len: usize,
+}
+
+impl NormalizedText {
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the byte range in the original text producing the given byte range of the normalized text
+    pub fn original_span(&self, span: Range<usize>) -> Range<usize> {
+        if span.is_empty() {
+            let start = self.origins.get(span.start).map_or(self.original_len, |origin| origin.start);
+            return start..start;
+        }
+        self.origins[span.start].start..self.origins[span.end - 1].end
+    }
+}
+
+impl Normalizer {
+    /// Initialize Normalizer with the default preset:
+    /// `ApostrophePolicy::Split`, `CasePolicy::Lowercase` and `AlphabetFilter`
+    pub fn new() -> Self {
+        Self::from(vec![
+            Box::new(ApostrophePolicy::Split),
+            Box::new(CasePolicy::Lowercase),
+            Box::new(AlphabetFilter),
+        ])
+    }
+
+    /// Initialize Normalizer with the given steps
+    pub fn from(steps: Vec<Box<dyn NormalizationStep>>) -> Self {
+        Self { steps }
+    }
+
+    /// Initialize Normalizer without any step, which leaves the text as it is
+    pub fn empty() -> Self {
+        Self::from(vec![])
+    }
+
+    /// Appends a step, run after every previous step
+    pub fn add_step(&mut self, step: Box<dyn NormalizationStep>) {
+        self.steps.push(step);
+    }
+
+    pub fn get_steps(&self) -> &[Box<dyn NormalizationStep>] {
+        &self.steps
+    }
+
+    /// Returns the normalized text
+    pub fn normalize(&self, text: &str) -> String {
+        self.normalize_with_offsets(text).text
+    }
+
+    /// Returns the normalized text, along with the location of each of its characters in the original text
+    pub fn normalize_with_offsets(&self, text: &str) -> NormalizedText {
+        let mut normalized_text = NormalizedText {
+            text: String::with_capacity(text.len()),
+            origins: Vec::with_capacity(text.len()),
+            original_len: text.len(),
+        };
+        let mut input = String::new();
+        let mut output = String::new();
+        let mut start = 0;
+        while start < text.len() {
+            let end = Self::cluster_end(text, start);
+            input.clear();
+            input.push_str(&text[start..end]);
+            for step in &self.steps {
+                output.clear();
+                step.normalize_cluster(&input, &mut output);
+                std::mem::swap(&mut input, &mut output);
+            }
+            normalized_text.text.push_str(&input);
+            normalized_text.origins.extend(std::iter::repeat_n(start..end, input.len()));
+            start = end;
+        }
+        normalized_text
+    }
+
+    /// Returns the end of the cluster starting at `start`: its first character and every following combining mark
+    fn cluster_end(text: &str, start: usize) -> usize {
+        let mut chars = text[start..].char_indices();
+        chars.next();
+        chars.find(|(_, c)| !is_combining_mark(*c))
+            .map_or(text.len(), |(offset, _)| start + offset)
+    }
+}
+
+impl Default for Normalizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod normalizer_test {
+    use super::*;
+    use crate::normalizer::dash_unification::DashUnification;
+    use crate::normalizer::digit_policy::DigitPolicy;
+    use crate::normalizer::strip_diacritics::StripDiacritics;
+    use crate::normalizer::unicode_folding::UnicodeFolding;
+
+    #[test]
+    fn should_normalize_with_default_preset() {
+        let normalizer = Normalizer::new();
+        assert_eq!(normalizer.get_steps().len(), 3);
+        assert_eq!(normalizer.normalize("Kafé"), "kaf ");
+        assert_eq!(normalizer.normalize("Qur'an Kafé buku–buku 2024"), "qur an kaf  buku buku 2024");
+    }
+
+    #[test]
+    fn should_leave_text_without_step() {
+        assert_eq!(Normalizer::empty().normalize("Qur'an"), "Qur'an");
+    }
+
+    #[test]
+    fn should_run_steps_in_order() {
+        let normalizer = Normalizer::from(vec![
+            Box::new(UnicodeFolding),
+            Box::new(StripDiacritics),
+            Box::new(DashUnification),
+            Box::new(ApostrophePolicy::Keep),
+            Box::new(DigitPolicy::Remove),
+            Box::new(CasePolicy::Lowercase),
+        ]);
+        assert_eq!(normalizer.normalize("Ｑｕｒ’an Kafé buku–buku 2024"), "qur'an kafe buku-buku ");
+    }
+
+    #[test]
+    fn should_map_normalized_text_to_original_text() {
+        let text = "Ｂｕｋｕ ﬁlm";
+        let mut normalizer = Normalizer::empty();
+        normalizer.add_step(Box::new(UnicodeFolding));
+        let normalized_text = normalizer.normalize_with_offsets(text);
+        assert_eq!(normalized_text.as_str(), "Buku film");
+        assert_eq!(&text[normalized_text.original_span(0..4)], "Ｂｕｋｕ");
+        assert_eq!(&text[normalized_text.original_span(5..9)], "ﬁlm");
+        assert_eq!(&text[normalized_text.original_span(5..6)], "ﬁ");
+        assert_eq!(normalized_text.original_span(9..9), 18..18);
+    }
+
+    #[test]
+    fn should_compose_decomposed_characters() {
+        let text = "Kafe\u{301} ini";
+        let normalizer = Normalizer::from(vec![Box::new(UnicodeFolding), Box::new(CasePolicy::Lowercase)]);
+        let normalized_text = normalizer.normalize_with_offsets(text);
+        assert_eq!(normalized_text.as_str(), "kafé ini");
+        assert_eq!(normalized_text.original_span(0..5), 0..6);
+        assert_eq!(normalized_text.original_span(3..5), 3..6);
+
+        let tokens = crate::tokenizer::Tokenizer::new().tokenize(normalized_text.as_str());
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].surface, "kafé");
+    }
+
+    #[test]
+    fn should_map_removed_characters() {
+        let text = "ma'af";
+        let normalizer = Normalizer::from(vec![Box::new(ApostrophePolicy::Remove)]);
+        let normalized_text = normalizer.normalize_with_offsets(text);
+        assert_eq!(normalized_text.as_str(), "maaf");
+        assert_eq!(normalized_text.original_span(0..4), 0..5);
+        assert_eq!(normalized_text.original_span(2..4), 3..5);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::normalizer::NormalizationStep;
+
+/// Replaces every character other than "a" to "z", "0" to "9", " " and "-" with a whitespace,
+/// like the `[^a-z0-9 -]` rule the stemmer always applied, e.g. "kafé" into "kaf ".
+///
+/// Uppercase letters are replaced as well, so the text is expected to be lowercased by a previous step.
+pub struct AlphabetFilter;
+
+impl NormalizationStep for AlphabetFilter {
+    fn normalize_char(&self, c: char, output: &mut String) {
+        output.push(if matches!(c, 'a'..='z' | '0'..='9' | ' ' | '-') { c } else { ' ' });
+    }
+}
+
+#[cfg(test)]
+mod alphabet_filter_test {
+    use super::*;
+
+    #[test]
+    fn should_replace_other_characters() {
+        let mut output = String::new();
+        for c in "kafé buku–buku, covid-19\tQur'an".chars() {
+            AlphabetFilter.normalize_char(c, &mut output);
+        }
+        assert_eq!(output, "kaf  buku buku  covid-19  ur an");
+    }
+}
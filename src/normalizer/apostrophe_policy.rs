@@ -0,0 +1,55 @@
+use crate::normalizer::NormalizationStep;
+
+/// Apostrophes recognized by the policy: "'", "’", "‘", "ʼ" and "`"
+const APOSTROPHES: [char; 5] = ['\'', '\u{2019}', '\u{2018}', '\u{2bc}', '`'];
+
+/// What to do with apostrophes, e.g. in "Qur'an" or "ma'af"
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum ApostrophePolicy {
+    /// Replaces apostrophes with whitespace, so "qur'an" is split into "qur" and "an"
+    #[default]
+    Split,
+    /// Removes apostrophes, so "qur'an" becomes "quran"
+    Remove,
+    /// Replaces every apostrophe with "'", so "qur’an" is kept as a single word "qur'an"
+    Keep,
+}
+
+impl NormalizationStep for ApostrophePolicy {
+    fn normalize_char(&self, c: char, output: &mut String) {
+        if !APOSTROPHES.contains(&c) {
+            output.push(c);
+            return;
+        }
+        match self {
+            ApostrophePolicy::Split => output.push(' '),
+            ApostrophePolicy::Remove => {},
+            ApostrophePolicy::Keep => output.push('\''),
+        }
+    }
+}
+
+#[cfg(test)]
+mod apostrophe_policy_test {
+    use super::*;
+
+    fn normalize(policy: ApostrophePolicy, text: &str) -> String {
+        let mut output = String::new();
+        for c in text.chars() {
+            policy.normalize_char(c, &mut output);
+        }
+        output
+    }
+
+    #[test]
+    fn should_apply_policy() {
+        assert_eq!(normalize(ApostrophePolicy::Split, "qur'an"), "qur an");
+        assert_eq!(normalize(ApostrophePolicy::Remove, "qur'an ma’af"), "quran maaf");
+        assert_eq!(normalize(ApostrophePolicy::Keep, "qur’an"), "qur'an");
+    }
+
+    #[test]
+    fn should_default_to_split() {
+        assert_eq!(ApostrophePolicy::default(), ApostrophePolicy::Split);
+    }
+}
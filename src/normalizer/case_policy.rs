@@ -0,0 +1,35 @@
+use crate::normalizer::NormalizationStep;
+
+/// What to do with letter case
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum CasePolicy {
+    /// Lowercases every letter
+    #[default]
+    Lowercase,
+    /// Keeps letters as they are.
+    ///
+    /// Words are still lowercased to be stemmed, but a word left unchanged by the stemmer keeps its case, e.g. "Jakarta".
+    Preserve,
+}
+
+impl NormalizationStep for CasePolicy {
+    fn normalize_char(&self, c: char, output: &mut String) {
+        match self {
+            CasePolicy::Lowercase => output.extend(c.to_lowercase()),
+            CasePolicy::Preserve => output.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod case_policy_test {
+    use super::*;
+
+    #[test]
+    fn should_apply_policy() {
+        let mut output = String::new();
+        CasePolicy::Lowercase.normalize_char('A', &mut output);
+        CasePolicy::Preserve.normalize_char('B', &mut output);
+        assert_eq!(output, "aB");
+    }
+}
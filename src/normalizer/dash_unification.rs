@@ -0,0 +1,31 @@
+use crate::normalizer::NormalizationStep;
+
+/// Replaces every dash and hyphen with "-", e.g. the en-dash in "buku–buku",
+/// so the reduplication is recognized as a hyphenated compound.
+pub struct DashUnification;
+
+impl DashUnification {
+    fn is_dash(&self, c: char) -> bool {
+        matches!(c, '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{fe58}' | '\u{fe63}' | '\u{ff0d}')
+    }
+}
+
+impl NormalizationStep for DashUnification {
+    fn normalize_char(&self, c: char, output: &mut String) {
+        output.push(if self.is_dash(c) { '-' } else { c });
+    }
+}
+
+#[cfg(test)]
+mod dash_unification_test {
+    use super::*;
+
+    #[test]
+    fn should_unify_dashes() {
+        let mut output = String::new();
+        for c in "buku–buku kupu‐kupu anak—anak".chars() {
+            DashUnification.normalize_char(c, &mut output);
+        }
+        assert_eq!(output, "buku-buku kupu-kupu anak-anak");
+    }
+}
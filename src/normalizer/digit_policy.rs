@@ -0,0 +1,43 @@
+use crate::normalizer::NormalizationStep;
+
+/// What to do with digits
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum DigitPolicy {
+    /// Keeps digits as they are
+    #[default]
+    Keep,
+    /// Removes every digit, e.g. "covid19" becomes "covid"
+    Remove,
+    /// Replaces every digit with "0", so numbers of the same shape are normalized alike, e.g. "2024" becomes "0000"
+    Mask,
+}
+
+impl NormalizationStep for DigitPolicy {
+    fn normalize_char(&self, c: char, output: &mut String) {
+        match self {
+            DigitPolicy::Remove if c.is_numeric() => {},
+            DigitPolicy::Mask if c.is_numeric() => output.push('0'),
+            _ => output.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod digit_policy_test {
+    use super::*;
+
+    fn normalize(policy: DigitPolicy, text: &str) -> String {
+        let mut output = String::new();
+        for c in text.chars() {
+            policy.normalize_char(c, &mut output);
+        }
+        output
+    }
+
+    #[test]
+    fn should_apply_policy() {
+        assert_eq!(normalize(DigitPolicy::Keep, "covid19"), "covid19");
+        assert_eq!(normalize(DigitPolicy::Remove, "covid19"), "covid");
+        assert_eq!(normalize(DigitPolicy::Mask, "tahun 2024"), "tahun 0000");
+    }
+}
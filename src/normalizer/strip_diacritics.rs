@@ -0,0 +1,28 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use crate::normalizer::NormalizationStep;
+
+/// Removes diacritics from letters, e.g. "kafé" into "kafe".
+///
+/// Letters are decomposed with Unicode NFD, and every combining mark is removed.
+pub struct StripDiacritics;
+
+impl NormalizationStep for StripDiacritics {
+    fn normalize_char(&self, c: char, output: &mut String) {
+        output.extend(std::iter::once(c).nfd().filter(|c| !is_combining_mark(*c)));
+    }
+}
+
+#[cfg(test)]
+mod strip_diacritics_test {
+    use super::*;
+
+    #[test]
+    fn should_strip_diacritics() {
+        let mut output = String::new();
+        for c in "kafé ñ e\u{301}".chars() {
+            StripDiacritics.normalize_char(c, &mut output);
+        }
+        assert_eq!(output, "kafe n e");
+    }
+}
@@ -0,0 +1,45 @@
+use unicode_normalization::UnicodeNormalization;
+use crate::normalizer::NormalizationStep;
+
+/// Folds compatibility characters with Unicode NFKC, e.g. full-width "ｂｕｋｕ" into "buku" and "ﬁ" into "fi".
+///
+/// Each letter is folded along with its combining marks, so "e" followed by U+0301 is composed into "é".
+pub struct UnicodeFolding;
+
+impl NormalizationStep for UnicodeFolding {
+    fn normalize_char(&self, c: char, output: &mut String) {
+        output.extend(std::iter::once(c).nfkc());
+    }
+
+    fn normalize_cluster(&self, cluster: &str, output: &mut String) {
+        output.extend(cluster.nfkc());
+    }
+}
+
+#[cfg(test)]
+mod unicode_folding_test {
+    use super::*;
+
+    #[test]
+    fn should_fold_full_width_characters() {
+        let mut output = String::new();
+        for c in "ｂｕｋｕ－１".chars() {
+            UnicodeFolding.normalize_char(c, &mut output);
+        }
+        assert_eq!(output, "buku-1");
+    }
+
+    #[test]
+    fn should_fold_ligature() {
+        let mut output = String::new();
+        UnicodeFolding.normalize_char('ﬁ', &mut output);
+        assert_eq!(output, "fi");
+    }
+
+    #[test]
+    fn should_compose_combining_marks() {
+        let mut output = String::new();
+        UnicodeFolding.normalize_cluster("e\u{301}", &mut output);
+        assert_eq!(output, "é");
+    }
+}
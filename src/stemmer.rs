@@ -1,35 +1,107 @@
+pub mod algorithm;
+pub mod cached_stemmer;
 pub mod context;
 pub mod confix_stripping;
+pub mod pipeline;
+pub mod stemmed_token;
+pub mod stemmer_builder;
+pub mod stemming_mode;
+pub mod tala_stemmer;
 
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::sync::Arc;
 use regex::Regex;
 use crate::dictionary::Dictionary;
+use crate::error::{Error, Result};
+use crate::lexicon::Lexicon;
 use crate::stemmer::context::Context;
+use crate::stemmer::context::candidate::Candidate;
+use crate::stemmer::context::derivation::{Derivation, DerivationStep};
+use crate::stemmer::context::removal::AffixType;
+use crate::stemmer::pipeline::Pipeline;
+use crate::stemmer::stemmed_token::StemmedToken;
+use crate::stemmer::stemmer_builder::StemmerBuilder;
+use crate::stemmer::tala_stemmer::TalaStemmer;
+use crate::normalizer::{NormalizedText, Normalizer};
+use crate::tokenizer::{Token, TokenKind, Tokenizer};
 
 pub struct Stemmer {
-    dictionary: Dictionary,
-    re_alphabet: Regex,
-    re_whitespaces: Regex,
+    dictionary: Box<dyn Lexicon>,
+    pipeline: Arc<Pipeline>,
+    fallback: Option<TalaStemmer>,
+    normalizer: Normalizer,
+    tokenizer: Tokenizer,
     re_is_plural: Regex,
+    re_plural_parts: Regex,
 }
 
 impl Stemmer {
     /// Initialize Stemmer with default dictionary
+    ///
+    /// The default dictionary is bundled into the crate,
+    /// so it does not depend on the current working directory.
+    #[cfg(feature = "bundled-dictionary")]
     pub fn new() -> Self {
+        Self::from(Dictionary::bundled())
+    }
+
+    /// Initialize Stemmer with given root word dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::stemmer::Stemmer;
+    /// let stemmer = Stemmer::from(Dictionary::from_list(vec!["baca"]));
+    /// assert_eq!(stemmer.stem(String::from("membaca")), "baca");
+    /// ```
+    pub fn from(dictionary: Dictionary) -> Self {
+        Self::with_lexicon(dictionary)
+    }
+
+    /// Initialize Stemmer with given root word store, e.g. an `FstDictionary`.
+    pub fn with_lexicon(lexicon: impl Lexicon + 'static) -> Self {
+        Self {
+            dictionary: Box::new(lexicon),
+            ..Self::empty()
+        }
+    }
+
+    /// Initialize Stemmer with given root word store and stemming pipeline.
+    ///
+    /// The pipeline can be shared by several stemmers.
+    pub fn with_pipeline(dictionary: impl Lexicon + 'static, pipeline: Arc<Pipeline>) -> Self {
         Self {
-            dictionary: Dictionary::from_file("src/data/kata-dasar.txt"),
+            dictionary: Box::new(dictionary),
+            pipeline,
             ..Self::empty()
         }
     }
 
+    /// Returns a builder to configure the dictionary and the visitor pipeline of a Stemmer.
+    pub fn builder() -> StemmerBuilder {
+        StemmerBuilder::new()
+    }
+
+    /// Initialize Stemmer with root word dictionary read from a text file,
+    /// or returns an error if the file cannot be read.
+    pub fn try_from_file(filename: &str) -> Result<Self> {
+        Ok(Self::from(Dictionary::try_from_file(filename)?))
+    }
+
     /// Initialize Stemmer with empty dictionary.
     ///
     /// Probably useful only for testing optimization
     pub fn empty() -> Self {
         Self {
-            dictionary: Dictionary::new(),
-            re_alphabet: Regex::new(r"[^a-z0-9 -]").unwrap(),
-            re_whitespaces: Regex::new(r"( +)").unwrap(),
+            dictionary: Box::new(Dictionary::new()),
+            pipeline: Pipeline::shared(),
+            fallback: None,
+            normalizer: Normalizer::new(),
+            tokenizer: Tokenizer::new(),
             re_is_plural: Regex::new(r"^(.*)-(ku|mu|nya|lah|kah|tah|pun)$").unwrap(),
+            re_plural_parts: Regex::new(r"^(.*)-(.*)$").unwrap(),
         }
     }
 
@@ -38,6 +110,11 @@ impl Stemmer {
         self.dictionary.len()
     }
 
+    /// Returns true if the stemmer dictionary is empty
+    pub fn is_empty(&self) -> bool {
+        self.dictionary.is_empty()
+    }
+
     /// Evaluates whether the word is plural or not.
     ///
     /// `word` must be at a lowercase state.
@@ -54,50 +131,438 @@ impl Stemmer {
             let first_group = captures.get(1).map_or("", |w| w.as_str());
             return first_group.contains("-");
         }
-        return word.contains("-");
+        word.contains('-')
     }
 
-    /// Removes symbols & characters other than alphabet
+    /// Returns the normalizer applied on the text before it is tokenized
+    pub fn get_normalizer(&self) -> &Normalizer {
+        &self.normalizer
+    }
+
+    /// Returns every token of the normalized text to be stemmed, i.e. every token but punctuations
+    fn tokenize<'t>(&self, normalized_text: &'t str) -> Vec<Token<'t>> {
+        let mut tokens = self.tokenizer.tokenize(normalized_text);
+        tokens.retain(|token| token.kind != TokenKind::Punctuation);
+        tokens
+    }
+
+    /// Normalizes the text, removes punctuations, and lowercases every word.
     ///
-    /// Text is applied with two regex rule:
-    /// - `r"[^a-z0-9 -]"`
-    /// - `r"( +)"`
+    /// Numbers and URLs left by the normalizer are kept as written in the original text.
     fn normalize_text(&self, text: String) -> String {
-        let lowercase_text = text.to_lowercase();
-        let alphabet_only = self.re_alphabet.replace_all(&lowercase_text, " ");
-        let single_whitespace_only = self.re_whitespaces.replace_all(&alphabet_only, " ");
-        single_whitespace_only.trim().to_string()
+        let normalized_text = self.normalizer.normalize_with_offsets(&text);
+        let normalized_tokens: Vec<String> = self.tokenize(normalized_text.as_str()).into_iter()
+            .map(|token| match token.kind.is_word() {
+                true => token.normalized,
+                false => text[normalized_text.original_span(token.span)].to_string(),
+            })
+            .collect();
+        normalized_tokens.join(" ")
     }
 
     /// Stem the given text.
+    ///
+    /// The text is normalized by the `Normalizer`, then split by the `Tokenizer`:
+    /// punctuations are removed, while numbers and URLs are kept as they are.
+    /// The default normalizer replaces every symbol with a whitespace, so numbers and URLs are split as well.
     pub fn stem(&self, text: String) -> String {
-        let normalized_text = self.normalize_text(text);
-        let words = normalized_text.split(" ");
-
-        let stemmed_words: Vec<String> = words.into_iter().map(|word| {
-            if self.is_plural(word) {
-                self.stem_plural_word(word)
-            } else {
-                self.stem_singular_word(word)
-            }
-        }).collect();
+        let context = RefCell::new(self.new_context(""));
+        self.stem_with(&text, &|word| self.stem_normalized_word(&mut context.borrow_mut(), word))
+    }
+
+    /// Same as `stem`, but each normalized word is stemmed by `stem_word`,
+    /// e.g. a cached lookup in `CachedStemmer`.
+    pub(crate) fn stem_with(&self, text: &str, stem_word: &dyn Fn(&str) -> String) -> String {
+        let normalized_text = self.normalizer.normalize_with_offsets(text);
+        let stemmed_words: Vec<String> = self.tokenize(normalized_text.as_str()).iter()
+            .map(|token| self.stem_token(text, &normalized_text, token, stem_word))
+            .collect();
         stemmed_words.join(" ")
     }
 
-    fn stem_singular_word(&self, word: &str) -> String {
-        let mut context = Context::new(word, &self.dictionary, None);
+    /// Stem a word or hyphenated compound token with `stem_word`,
+    /// other tokens are returned as written in the original text.
+    ///
+    /// A word left unchanged by the stemming process is returned as written in the normalized text.
+    fn stem_token(&self, text: &str, normalized_text: &NormalizedText, token: &Token, stem_word: &dyn Fn(&str) -> String) -> String {
+        if !token.kind.is_word() {
+            return text[normalized_text.original_span(token.span.clone())].to_string();
+        }
+        let root_word = stem_word(&token.normalized);
+        if root_word == token.normalized {
+            token.surface.to_string()
+        } else {
+            root_word
+        }
+    }
+
+    /// Stem the given text, and returns each of its words along with its stem and location.
+    ///
+    /// The text is split the same way as `stem`, so the stems are the same as the words returned by `stem`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::stemmer::Stemmer;
+    /// let stemmer = Stemmer::from(Dictionary::from_list(vec!["baca", "buku"]));
+    /// let tokens = stemmer.stem_tokens("Dia membaca bukunya.");
+    /// assert_eq!(tokens[1].surface, "membaca");
+    /// assert_eq!(tokens[1].span, 4..11);
+    /// assert_eq!(tokens[1].stem, "baca");
+    /// assert_eq!(tokens[1].removed_prefixes, vec!["mem"]);
+    /// assert_eq!(tokens[2].removed_suffixes, vec!["nya"]);
+    /// assert_eq!(tokens[0].is_dictionary_confirmed, false);
+    /// ```
+    pub fn stem_tokens(&self, text: &str) -> Vec<StemmedToken> {
+        let normalized_text = self.normalizer.normalize_with_offsets(text);
+        let mut context = self.new_context("");
+        self.tokenize(normalized_text.as_str()).into_iter()
+            .map(|token| {
+                let word = token.normalized.as_str();
+                let mut stemmed_token = match token.kind.is_word() {
+                    true if self.is_plural(word) => self.stem_plural_token(&mut context, word),
+                    true => self.stem_singular_token(&mut context, word),
+                    false => self.unstemmed_token(word),
+                };
+                if stemmed_token.stem == word {
+                    stemmed_token.stem = token.surface.to_string();
+                }
+                stemmed_token.span = normalized_text.original_span(token.span);
+                stemmed_token.surface = text[stemmed_token.span.clone()].to_string();
+                if !token.kind.is_word() {
+                    stemmed_token.stem = stemmed_token.surface.clone();
+                }
+                stemmed_token
+            })
+            .collect()
+    }
+
+    /// Stem a single word, or returns an error if the given text is not a single word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::stemmer::Stemmer;
+    /// let stemmer = Stemmer::from(Dictionary::from_list(vec!["baca"]));
+    /// assert_eq!(stemmer.try_stem_word("Membaca").unwrap(), "baca");
+    /// assert_eq!(stemmer.try_stem_word("membaca buku").is_err(), true);
+    /// assert_eq!(stemmer.try_stem_word("...").is_err(), true);
+    /// ```
+    pub fn try_stem_word(&self, word: &str) -> Result<String> {
+        self.try_stem_word_with(word, &|word| self.stem_normalized_word(&mut self.new_context(word), word))
+    }
+
+    /// Same as `try_stem_word`, but the normalized word is stemmed by `stem_word`.
+    ///
+    /// `stem_word` is not called if the given text is not a single word.
+    pub(crate) fn try_stem_word_with(&self, word: &str, stem_word: &dyn Fn(&str) -> String) -> Result<String> {
+        let normalized_word = self.normalizer.normalize_with_offsets(word);
+        let token = self.single_token(word, &normalized_word)?;
+        Ok(self.stem_token(word, &normalized_word, &token, stem_word))
+    }
+
+    /// Returns the only token of the normalized word, or an error if the given text is not a single word
+    fn single_token<'t>(&self, word: &str, normalized_word: &'t NormalizedText) -> Result<Token<'t>> {
+        let mut tokens = self.tokenize(normalized_word.as_str());
+        match tokens.len() {
+            1 => Ok(tokens.remove(0)),
+            _ => Err(Error::InvalidWord(word.to_string())),
+        }
+    }
+
+    /// Returns a new context sharing the dictionary and pipeline of this stemmer
+    fn new_context(&self, word: &str) -> Context<'_> {
+        Context::new(word, self.dictionary.as_ref(), Some(&self.pipeline))
+    }
+
+    /// Stem a single word that is already normalized by `normalize_text`.
+    ///
+    /// The given context is reset and reused for every part of the word.
+    fn stem_normalized_word(&self, context: &mut Context, word: &str) -> String {
+        if self.is_plural(word) {
+            self.stem_plural_word(context, word)
+        } else {
+            self.stem_singular_word(context, word)
+        }
+    }
+
+    /// Stem a single word, and explain how its resulting word is derived.
+    ///
+    /// The derivation lists every visitor run, affix removal, dictionary lookup
+    /// and rule precedence taken by the stemming process.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::stemmer::Stemmer;
+    /// let stemmer = Stemmer::from(Dictionary::from_list(vec!["bahagia"]));
+    /// let derivation = stemmer.stem_word_explained("Membahagiakan");
+    /// assert_eq!(derivation.resulting_word, "bahagia");
+    /// assert_eq!(derivation.get_removals().len(), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given text is not a single word, see `try_stem_word_explained` for the fallible version.
+    pub fn stem_word_explained(&self, word: &str) -> Derivation {
+        match self.try_stem_word_explained(word) {
+            Ok(derivation) => derivation,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Same as `stem_word_explained`, but returns an error if the given text is not a single word,
+    /// the same way as `try_stem_word`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::stemmer::Stemmer;
+    /// let stemmer = Stemmer::from(Dictionary::from_list(vec!["baca"]));
+    /// assert_eq!(stemmer.try_stem_word_explained("Membaca").unwrap().resulting_word, "baca");
+    /// assert_eq!(stemmer.try_stem_word_explained("membaca buku").is_err(), true);
+    /// assert_eq!(stemmer.try_stem_word_explained("").is_err(), true);
+    /// ```
+    pub fn try_stem_word_explained(&self, word: &str) -> Result<Derivation> {
+        let normalized_word = self.normalizer.normalize_with_offsets(word);
+        let token = self.single_token(word, &normalized_word)?;
+        if self.is_plural(&token.normalized) {
+            Ok(self.explain_plural_word(&token.normalized))
+        } else {
+            Ok(self.explain_singular_word(&token.normalized))
+        }
+    }
+
+    fn explain_singular_word(&self, word: &str) -> Derivation {
+        let mut context = self.new_context(word);
+        context.enable_derivation();
         context.execute();
-        context.get_resulting_word()
+        let mut derivation = context.get_derivation().unwrap();
+        if let (false, Some(fallback)) = (context.is_root_found(), &self.fallback) {
+            derivation.resulting_word = fallback.stem_word(word);
+            derivation.steps.push(DerivationStep::RuleBasedFallback { result: derivation.resulting_word.clone() });
+        }
+        derivation
     }
 
-    fn stem_plural_word(&self, word: &str) -> String {
-        todo!()
+    /// Same as `stem_plural_word`, but keeps the derivation of each part.
+    fn explain_plural_word(&self, plural: &str) -> Derivation {
+        let mut derivation = Derivation {
+            original_word: plural.to_string(),
+            resulting_word: plural.to_string(),
+            steps: vec![],
+            parts: vec![],
+        };
+        let (first_part, second_part) = match self.split_plural_word(plural) {
+            Some(parts) => parts,
+            None => return derivation,
+        };
+
+        let first_derivation = self.explain_singular_word(&first_part);
+        let mut second_derivation = self.explain_singular_word(&second_part);
+        if !self.dictionary.contains(&second_part) && second_derivation.resulting_word == second_part {
+            second_derivation = self.explain_singular_word(&format!("me{}", second_part));
+        }
+
+        if first_derivation.resulting_word == second_derivation.resulting_word {
+            derivation.resulting_word = first_derivation.resulting_word.clone();
+        }
+        derivation.parts = vec![first_derivation, second_derivation];
+        derivation
+    }
+
+    /// Returns every root word the affix search finds for the given word, each with the affixes removed to reach it.
+    ///
+    /// The candidates are ranked by their frequency in the dictionary, most frequent first.
+    /// Candidates with the same frequency are kept in the order they are found,
+    /// so the root word returned by `stem` comes first when no frequency is given.
+    /// For plural word, only the root words shared by both parts are returned.
+    ///
+    /// Returns an empty list if no root word is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::stemmer::Stemmer;
+    /// let stemmer = Stemmer::from(Dictionary::from_frequency_list(vec![("beruang", 3), ("uang", 120)]));
+    /// let candidates = stemmer.candidates("beruang");
+    /// assert_eq!(candidates[0].root_word, "uang");
+    /// assert_eq!(candidates[0].removals[0].get_removed_part(), "ber");
+    /// assert_eq!(candidates[1].root_word, "beruang");
+    /// ```
+    pub fn candidates(&self, word: &str) -> Vec<Candidate> {
+        let word = self.normalize_text(word.to_string());
+        let mut context = self.new_context("");
+        context.enable_candidates();
+
+        let mut candidates = if self.is_plural(&word) {
+            self.find_plural_candidates(&mut context, &word)
+        } else {
+            self.find_candidates(&mut context, &word)
+        };
+        candidates.sort_by_key(|candidate| Reverse(candidate.frequency));
+        candidates
+    }
+
+    fn find_candidates(&self, context: &mut Context, word: &str) -> Vec<Candidate> {
+        context.reset(word);
+        context.execute();
+        context.get_candidates().map(|candidates| candidates.to_vec()).unwrap_or_default()
+    }
+
+    fn find_plural_candidates(&self, context: &mut Context, plural: &str) -> Vec<Candidate> {
+        let Some((first_part, second_part)) = self.split_plural_word(plural) else {
+            return vec![];
+        };
+
+        let mut second_candidates = self.find_candidates(context, &second_part);
+        // meniru-nirukan -> tiru
+        if second_candidates.is_empty() {
+            second_candidates = self.find_candidates(context, &format!("me{}", second_part));
+        }
+
+        let mut first_candidates = self.find_candidates(context, &first_part);
+        first_candidates.retain(|candidate| second_candidates.iter()
+            .any(|second_candidate| second_candidate.root_word == candidate.root_word));
+        first_candidates
+    }
+
+    fn stem_singular_word(&self, context: &mut Context, word: &str) -> String {
+        context.reset(word);
+        context.execute();
+        match (context.is_root_found(), &self.fallback) {
+            (false, Some(fallback)) => fallback.stem_word(word),
+            _ => context.get_resulting_word(),
+        }
+    }
+
+    /// Same as `stem_singular_word`, but keeps the removed affixes.
+    ///
+    /// The surface and span of the returned token are left empty.
+    fn stem_singular_token(&self, context: &mut Context, word: &str) -> StemmedToken {
+        let stem = self.stem_singular_word(context, word);
+        let mut token = StemmedToken {
+            is_dictionary_confirmed: context.is_root_found() && self.dictionary.contains(&stem),
+            stem,
+            ..self.unstemmed_token(word)
+        };
+        if context.is_root_found() {
+            for removal in context.get_removals() {
+                let removed_part = removal.get_removed_part().to_string();
+                match removal.get_affix_type() {
+                    AffixType::DerivationalPrefix => token.removed_prefixes.push(removed_part),
+                    _ => token.removed_suffixes.push(removed_part),
+                }
+            }
+        }
+        token
+    }
+
+    /// Returns a token whose stem is the word itself, without any removed affix.
+    ///
+    /// The surface and span of the returned token are left empty.
+    fn unstemmed_token(&self, word: &str) -> StemmedToken {
+        StemmedToken {
+            surface: String::new(),
+            span: 0..0,
+            stem: word.to_string(),
+            removed_prefixes: vec![],
+            removed_suffixes: vec![],
+            is_dictionary_confirmed: false,
+        }
+    }
+
+    /// Same as `stem_plural_word`, but keeps the removed affixes:
+    /// the prefixes removed from the first part, and the suffixes removed from the second part.
+    ///
+    /// The surface and span of the returned token are left empty.
+    fn stem_plural_token(&self, context: &mut Context, plural: &str) -> StemmedToken {
+        let plural_token = self.unstemmed_token(plural);
+        let (first_part, second_part) = match self.split_plural_word(plural) {
+            Some(parts) => parts,
+            None => return plural_token,
+        };
+
+        let first_token = self.stem_singular_token(context, &first_part);
+        let mut second_token = self.stem_singular_token(context, &second_part);
+        if !self.dictionary.contains(&second_part) && second_token.stem == second_part {
+            second_token = self.stem_singular_token(context, &format!("me{}", second_part));
+        }
+
+        if first_token.stem == second_token.stem {
+            StemmedToken {
+                is_dictionary_confirmed: first_token.is_dictionary_confirmed && second_token.is_dictionary_confirmed,
+                removed_suffixes: second_token.removed_suffixes,
+                ..first_token
+            }
+        } else {
+            plural_token
+        }
+    }
+
+    /// Splits a plural word into its two repeated parts.
+    ///
+    /// A trailing "-ku", "-mu", "-nya", "-lah", "-kah", "-tah" or "-pun" is kept on the second part,
+    /// so "malaikat-malaikat-nya" is split into "malaikat" and "malaikat-nya".
+    fn split_plural_word(&self, plural: &str) -> Option<(String, String)> {
+        let captures = self.re_plural_parts.captures(plural)?;
+        let first_part = captures.get(1).map_or("", |w| w.as_str());
+        let second_part = captures.get(2).map_or("", |w| w.as_str());
+
+        if self.re_is_plural.is_match(plural) {
+            if let Some(inner_captures) = self.re_plural_parts.captures(first_part) {
+                let inner_first_part = inner_captures.get(1).map_or("", |w| w.as_str());
+                let inner_second_part = inner_captures.get(2).map_or("", |w| w.as_str());
+                return Some((inner_first_part.to_string(), format!("{}-{}", inner_second_part, second_part)));
+            }
+        }
+        Some((first_part.to_string(), second_part.to_string()))
+    }
+
+    /// Stem a plural (reduplicated) word, e.g. "kucing-kucing" or "bertemu-temu".
+    ///
+    /// Both parts are stemmed separately, and the plural word is returned as-is
+    /// if they do not share the same root word.
+    fn stem_plural_word(&self, context: &mut Context, plural: &str) -> String {
+        let (first_part, second_part) = match self.split_plural_word(plural) {
+            Some(parts) => parts,
+            None => return plural.to_string(),
+        };
+
+        // berbalas-balasan -> balas
+        let first_root_word = self.stem_singular_word(context, &first_part);
+        let mut second_root_word = self.stem_singular_word(context, &second_part);
+
+        // meniru-nirukan -> tiru
+        if !self.dictionary.contains(&second_part) && second_root_word == second_part {
+            second_root_word = self.stem_singular_word(context, &format!("me{}", second_part));
+        }
+
+        if first_root_word == second_root_word {
+            first_root_word
+        } else {
+            plural.to_string()
+        }
+    }
+}
+
+#[cfg(feature = "bundled-dictionary")]
+impl Default for Stemmer {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 #[cfg(test)]
 mod normalize_text_test {
     use super::*;
+    use crate::normalizer::apostrophe_policy::ApostrophePolicy;
+    use crate::normalizer::case_policy::CasePolicy;
 
     #[test]
     fn should_lowercase_text() {
@@ -115,6 +580,30 @@ mod normalize_text_test {
         assert_eq!(normalized_text, "ayam kambing serta kucing semuanya berbahagia")
     }
 
+    #[test]
+    fn should_split_numbers_and_urls_by_default() {
+        let stemmer = Stemmer::empty();
+        let string = String::from("Ada 1.000 buku di https://example.com/Buku!");
+        let normalized_text = stemmer.normalize_text(string);
+        assert_eq!(normalized_text, "ada 1 000 buku di https example com buku")
+    }
+
+    #[test]
+    fn should_keep_numbers_and_urls_without_alphabet_filter() {
+        let normalizer = Normalizer::from(vec![Box::new(ApostrophePolicy::Split), Box::new(CasePolicy::Lowercase)]);
+        let stemmer = Stemmer::builder().dictionary(Dictionary::new()).normalizer(normalizer).build();
+        let string = String::from("Ada 1.000 buku di https://example.com/Buku!");
+        let normalized_text = stemmer.normalize_text(string);
+        assert_eq!(normalized_text, "ada 1.000 buku di https://example.com/Buku")
+    }
+
+    #[test]
+    fn should_drop_accented_letters_by_default() {
+        let stemmer = Stemmer::empty();
+        assert_eq!(stemmer.normalize_text(String::from("Kafé")), "kaf");
+        assert_eq!(stemmer.normalize_text(String::from("buku–buku")), "buku buku");
+    }
+
     #[test]
     fn should_remove_multiple_whitespace() {
         let stemmer = Stemmer::empty();
@@ -124,7 +613,7 @@ mod normalize_text_test {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "bundled-dictionary"))]
 mod is_plural_test {
     use super::*;
 
@@ -153,6 +642,471 @@ mod is_plural_test {
 }
 
 #[cfg(test)]
+mod try_stem_word_test {
+    use super::*;
+
+    #[test]
+    fn should_stem_word() {
+        let stemmer = Stemmer::from(Dictionary::from_list(vec!["kucing", "baca"]));
+        assert_eq!(stemmer.try_stem_word("membaca").unwrap(), "baca");
+        assert_eq!(stemmer.try_stem_word("Kucing-kucing").unwrap(), "kucing");
+    }
+
+    #[test]
+    fn should_return_error_on_invalid_word() {
+        let stemmer = Stemmer::from(Dictionary::from_list(vec!["kucing"]));
+        assert_eq!(matches!(stemmer.try_stem_word(""), Err(Error::InvalidWord(_))), true);
+        assert_eq!(matches!(stemmer.try_stem_word("?!"), Err(Error::InvalidWord(_))), true);
+        assert_eq!(matches!(stemmer.try_stem_word("kucing ayam"), Err(Error::InvalidWord(_))), true);
+    }
+
+    #[test]
+    fn should_return_error_on_invalid_file() {
+        assert_eq!(matches!(Stemmer::try_from_file("tests/invalid_file"), Err(Error::Io(_))), true);
+    }
+}
+
+#[cfg(test)]
+mod candidates_test {
+    use super::*;
+
+    #[test]
+    fn should_rank_candidates_by_frequency() {
+        let stemmer = Stemmer::from(Dictionary::from_frequency_list(vec![("beruang", 3), ("uang", 120)]));
+        let candidates = stemmer.candidates("Beruang");
+        let root_words: Vec<&str> = candidates.iter().map(|candidate| candidate.root_word.as_str()).collect();
+        assert_eq!(root_words, vec!["uang", "beruang"]);
+        assert_eq!(candidates[1].removals.len(), 0);
+    }
+
+    #[test]
+    fn should_keep_stemming_order_on_same_frequency() {
+        let stemmer = Stemmer::from(Dictionary::from_list(vec!["beruang", "uang"]));
+        let candidates = stemmer.candidates("beruang");
+        assert_eq!(candidates[0].root_word, stemmer.stem(String::from("beruang")));
+        assert_eq!(candidates.len(), 2);
+    }
+
+    #[test]
+    fn should_return_candidates_shared_by_plural_parts() {
+        let stemmer = Stemmer::from(Dictionary::from_list(vec!["balas", "tiru"]));
+        assert_eq!(stemmer.candidates("berbalas-balasan")[0].root_word, "balas");
+        assert_eq!(stemmer.candidates("meniru-nirukan")[0].root_word, "tiru");
+        assert_eq!(stemmer.candidates("kucing-ayam").len(), 0);
+    }
+
+    #[test]
+    fn should_return_empty_list_if_no_root_word_is_found() {
+        let stemmer = Stemmer::from(Dictionary::from_list(vec!["baca"]));
+        assert_eq!(stemmer.candidates("menulis").len(), 0);
+    }
+}
+
+#[cfg(test)]
+mod stem_tokens_test {
+    use super::*;
+    use crate::normalizer::apostrophe_policy::ApostrophePolicy;
+    use crate::normalizer::case_policy::CasePolicy;
+    use crate::normalizer::unicode_folding::UnicodeFolding;
+
+    fn stemmer() -> Stemmer {
+        Stemmer::from(Dictionary::from_list(vec!["baca", "buku", "balas", "tiru", "kucing"]))
+    }
+
+    #[test]
+    fn should_locate_each_word() {
+        let text = "Kucing, BUKU-buku... (dibaca)";
+        let tokens = stemmer().stem_tokens(text);
+        assert_eq!(tokens.len(), 3);
+        for token in &tokens {
+            assert_eq!(&text[token.span.clone()], token.surface);
+        }
+        assert_eq!(tokens[1].surface, "BUKU-buku");
+        assert_eq!(tokens[1].stem, "buku");
+        assert_eq!(tokens[2].span, 22..28);
+    }
+
+    #[test]
+    fn should_keep_offsets_of_multi_byte_characters() {
+        let text = "Kafé membaca";
+        let tokens = stemmer().stem_tokens(text);
+        let surfaces: Vec<&str> = tokens.iter().map(|token| token.surface.as_str()).collect();
+        assert_eq!(surfaces, vec!["Kaf", "membaca"]);
+        assert_eq!(tokens[1].span, 6..13);
+
+        let normalizer = Normalizer::from(vec![Box::new(CasePolicy::Lowercase)]);
+        let stemmer = Stemmer::builder().dictionary(Dictionary::from_list(vec!["baca"])).normalizer(normalizer).build();
+        assert_eq!(stemmer.stem_tokens(text)[0].surface, "Kafé");
+    }
+
+    #[test]
+    fn should_return_removed_affixes() {
+        let tokens = stemmer().stem_tokens("dibacakannya berbalas-balasan meniru-nirukan");
+        assert_eq!(tokens[0].removed_prefixes, vec!["di"]);
+        assert_eq!(tokens[0].removed_suffixes, vec!["nya", "kan"]);
+        assert_eq!(tokens[1].stem, "balas");
+        assert_eq!(tokens[1].removed_prefixes, vec!["ber"]);
+        assert_eq!(tokens[1].removed_suffixes, vec!["an"]);
+        assert_eq!(tokens[2].stem, "tiru");
+        assert_eq!(tokens[2].removed_prefixes, vec!["men"]);
+        assert_eq!(tokens[2].removed_suffixes, vec!["kan"]);
+    }
+
+    #[test]
+    fn should_flag_word_not_found_in_dictionary() {
+        let tokens = stemmer().stem_tokens("menulis kucing-ayam kucing");
+        assert_eq!(tokens[0].stem, "menulis");
+        assert_eq!(tokens[0].is_dictionary_confirmed, false);
+        assert_eq!(tokens[0].removed_prefixes.len(), 0);
+        assert_eq!(tokens[1].stem, "kucing-ayam");
+        assert_eq!(tokens[1].is_dictionary_confirmed, false);
+        assert_eq!(tokens[2].is_dictionary_confirmed, true);
+    }
+
+    #[test]
+    fn should_give_same_stems_as_stem() {
+        let stemmer = stemmer();
+        let text = "Dia membaca buku-bukunya, lalu berbalas-balasan surat!";
+        let stems: Vec<String> = stemmer.stem_tokens(text).into_iter().map(|token| token.stem).collect();
+        assert_eq!(stems.join(" "), stemmer.stem(String::from(text)));
+    }
+
+    #[test]
+    fn should_return_empty_list_on_empty_text() {
+        assert_eq!(stemmer().stem_tokens(" ... ").len(), 0);
+    }
+
+    #[test]
+    fn should_keep_urls_as_written() {
+        let normalizer = Normalizer::from(vec![Box::new(CasePolicy::Lowercase)]);
+        let stemmer = Stemmer::builder().dictionary(Dictionary::from_list(vec!["baca", "buku"])).normalizer(normalizer).build();
+        let text = "Membaca https://example.com/Buku";
+        assert_eq!(stemmer.stem(String::from(text)), "baca https://example.com/Buku");
+        assert_eq!(stemmer.stem_tokens(text)[1].stem, "https://example.com/Buku");
+        assert_eq!(stemmer.try_stem_word("www.Example.com/Buku").unwrap(), "www.Example.com/Buku");
+    }
+
+    #[test]
+    fn should_locate_words_of_normalized_text() {
+        let normalizer = Normalizer::from(vec![
+            Box::new(UnicodeFolding),
+            Box::new(ApostrophePolicy::Remove),
+            Box::new(CasePolicy::Lowercase),
+        ]);
+        let stemmer = Stemmer::builder().dictionary(Dictionary::from_list(vec!["maaf", "buku"])).normalizer(normalizer).build();
+        let text = "Ma'afkan ｂｕｋｕｎｙａ";
+        let tokens = stemmer.stem_tokens(text);
+        assert_eq!(tokens[0].surface, "Ma'afkan");
+        assert_eq!(tokens[0].stem, "maaf");
+        assert_eq!(tokens[1].surface, "ｂｕｋｕｎｙａ");
+        assert_eq!(tokens[1].span, 9..30);
+        assert_eq!(tokens[1].stem, "buku");
+    }
+
+    #[test]
+    fn should_keep_case_of_unchanged_word() {
+        let normalizer = Normalizer::from(vec![Box::new(CasePolicy::Preserve)]);
+        let stemmer = Stemmer::builder().dictionary(Dictionary::from_list(vec!["baca"])).normalizer(normalizer).build();
+        let tokens = stemmer.stem_tokens("Membaca di Jakarta");
+        let stems: Vec<&str> = tokens.iter().map(|token| token.stem.as_str()).collect();
+        assert_eq!(stems, vec!["baca", "di", "Jakarta"]);
+        assert_eq!(stemmer.stem(String::from("Membaca di Jakarta")), "baca di Jakarta");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_to_json() {
+        let tokens = stemmer().stem_tokens("membaca");
+        let json = serde_json::to_value(&tokens[0]).unwrap();
+        assert_eq!(json["stem"], "baca");
+        assert_eq!(json["span"]["start"], 0);
+        assert_eq!(json["span"]["end"], 7);
+    }
+}
+
+#[cfg(test)]
+mod pipeline_test {
+    use super::*;
+
+    #[test]
+    fn should_share_default_pipeline() {
+        let first_stemmer = Stemmer::from(Dictionary::from_list(vec!["baca"]));
+        let second_stemmer = Stemmer::from(Dictionary::from_list(vec!["tulis"]));
+        assert_eq!(Arc::ptr_eq(&first_stemmer.pipeline, &second_stemmer.pipeline), true);
+    }
+
+    #[test]
+    fn should_stem_with_given_pipeline() {
+        let pipeline = Arc::new(Pipeline::default());
+        let stemmer = Stemmer::with_pipeline(Dictionary::from_list(vec!["baca", "tulis"]), Arc::clone(&pipeline));
+        assert_eq!(Arc::ptr_eq(&stemmer.pipeline, &pipeline), true);
+        assert_eq!(stemmer.stem(String::from("membaca dan menulis, baca-membaca")), "baca dan tulis baca");
+    }
+}
+
+#[cfg(test)]
+mod split_plural_word_test {
+    use super::*;
+
+    #[test]
+    fn should_split_plural_word() {
+        let stemmer = Stemmer::empty();
+        assert_eq!(stemmer.split_plural_word("kucing-kucing"), Some((String::from("kucing"), String::from("kucing"))));
+        assert_eq!(stemmer.split_plural_word("bertemu-temu"), Some((String::from("bertemu"), String::from("temu"))));
+    }
+
+    #[test]
+    fn should_keep_suffix_on_second_part() {
+        let stemmer = Stemmer::empty();
+        assert_eq!(stemmer.split_plural_word("kucing-kucing-ku"), Some((String::from("kucing"), String::from("kucing-ku"))));
+        assert_eq!(stemmer.split_plural_word("nikmat-nikmat-nya"), Some((String::from("nikmat"), String::from("nikmat-nya"))));
+    }
+
+    #[test]
+    fn should_not_split_singular_word() {
+        let stemmer = Stemmer::empty();
+        assert_eq!(stemmer.split_plural_word("kucing"), None);
+    }
+}
+
+#[cfg(all(test, feature = "bundled-dictionary"))]
+mod stem_plural_word_test {
+    use super::*;
+
+    #[test]
+    fn should_stem_plural_word() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("kucing-kucing")), "kucing");
+        assert_eq!(stemmer.stem(String::from("Rumah-rumah")), "rumah");
+    }
+
+    #[test]
+    fn should_return_plural_word_if_root_words_differ() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("kucing-ayam")), "kucing-ayam");
+    }
+}
+
+#[cfg(all(test, feature = "bundled-dictionary"))]
+mod stem_suffixed_word_test {
+    use super::*;
+
+    #[test]
+    fn should_remove_suffix() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("bacalah")), "baca");
+        assert_eq!(stemmer.stem(String::from("bukunya")), "buku");
+        assert_eq!(stemmer.stem(String::from("sakiti")), "sakit");
+        assert_eq!(stemmer.stem(String::from("tindakan")), "tindak");
+    }
+
+    #[test]
+    fn should_remove_stacked_suffixes() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("pinjamkannya")), "pinjam");
+        assert_eq!(stemmer.stem(String::from("pinjamkanlah")), "pinjam");
+        assert_eq!(stemmer.stem(String::from("bukunyalah")), "buku");
+    }
+
+    #[test]
+    fn should_remove_prefix_and_suffix() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("membacakan")), "baca");
+        assert_eq!(stemmer.stem(String::from("dipertemukan")), "temu");
+        assert_eq!(stemmer.stem(String::from("memperbaiki")), "baik");
+    }
+
+    #[test]
+    fn should_stem_plural_word_with_suffix() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("kucing-kucing-ku")), "kucing");
+        assert_eq!(stemmer.stem(String::from("nikmat-nikmat-Nya")), "nikmat");
+    }
+}
+
+#[cfg(all(test, feature = "bundled-dictionary"))]
+mod loop_pengembalian_akhiran_test {
+    use super::*;
+
+    #[test]
+    fn should_restore_over_stemmed_suffix() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("berlari")), "lari");
+        assert_eq!(stemmer.stem(String::from("bertemu")), "temu");
+        assert_eq!(stemmer.stem(String::from("pemberdayaan")), "daya");
+    }
+
+    #[test]
+    fn should_restore_kan_as_k_an() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("penolakan")), "tolak");
+        assert_eq!(stemmer.stem(String::from("pelanggan")), "langgan");
+    }
+
+    #[test]
+    fn should_stem_affixed_reduplication() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("bertemu-temu")), "temu");
+    }
+}
+
+#[cfg(all(test, feature = "bundled-dictionary"))]
+mod stem_prefixed_word_test {
+    use super::*;
+
+    #[test]
+    fn should_remove_plain_prefix() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("dibaca")), "baca");
+        assert_eq!(stemmer.stem(String::from("kekasih")), "kasih");
+        assert_eq!(stemmer.stem(String::from("sebuah")), "buah");
+    }
+
+    #[test]
+    fn should_remove_disambiguated_prefix() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("bermain")), "main");
+        assert_eq!(stemmer.stem(String::from("belajar")), "ajar");
+        assert_eq!(stemmer.stem(String::from("bekerja")), "kerja");
+        assert_eq!(stemmer.stem(String::from("terbuka")), "buka");
+        assert_eq!(stemmer.stem(String::from("membaca")), "baca");
+        assert_eq!(stemmer.stem(String::from("memukul")), "pukul");
+        assert_eq!(stemmer.stem(String::from("menulis")), "tulis");
+        assert_eq!(stemmer.stem(String::from("menyapu")), "sapu");
+        assert_eq!(stemmer.stem(String::from("mengambil")), "ambil");
+        assert_eq!(stemmer.stem(String::from("mengupas")), "kupas");
+        assert_eq!(stemmer.stem(String::from("pelajar")), "ajar");
+        assert_eq!(stemmer.stem(String::from("penyanyi")), "nyanyi");
+        assert_eq!(stemmer.stem(String::from("petani")), "tani");
+    }
+
+    #[test]
+    fn should_remove_stacked_prefixes() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("diperlebar")), "lebar");
+        assert_eq!(stemmer.stem(String::from("memperlebar")), "lebar");
+    }
+
+    #[test]
+    fn should_stem_affixed_reduplication() {
+        let stemmer = Stemmer::new();
+        assert_eq!(stemmer.stem(String::from("berbalas-balas")), "balas");
+        assert_eq!(stemmer.stem(String::from("tembak-menembak")), "tembak");
+    }
+}
+
+#[cfg(all(test, feature = "bundled-dictionary"))]
+mod stem_word_explained_test {
+    use super::*;
+    use crate::stemmer::context::derivation::{DerivationStep, RulePrecedence};
+    use crate::stemmer::context::removal::AffixType;
+    use crate::stemmer::context::visitor::{VisitorResult, VisitorType};
+
+    #[test]
+    fn should_return_error_if_not_a_single_word() {
+        let stemmer = Stemmer::new();
+        for text in ["membaca buku", "", " ... "] {
+            let result = stemmer.try_stem_word_explained(text);
+            assert_eq!(matches!(result, Err(Error::InvalidWord(word)) if word == text), true);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid word: \"membaca buku\"")]
+    fn should_panic_if_not_a_single_word() {
+        Stemmer::new().stem_word_explained("membaca buku");
+    }
+
+    #[test]
+    fn should_explain_root_word() {
+        let stemmer = Stemmer::new();
+        let derivation = stemmer.stem_word_explained("kucing");
+        assert_eq!(derivation.original_word, "kucing");
+        assert_eq!(derivation.resulting_word, "kucing");
+        assert_eq!(derivation.steps[0], DerivationStep::DictionaryLookup { word: String::from("kucing"), found: true });
+        assert_eq!(derivation.get_removals().len(), 0);
+    }
+
+    #[test]
+    fn should_explain_removals() {
+        let stemmer = Stemmer::new();
+        let derivation = stemmer.stem_word_explained("Membahagiakan");
+        assert_eq!(derivation.resulting_word, "bahagia");
+
+        let removals = derivation.get_removals();
+        assert_eq!(removals.len(), 2);
+        assert_eq!(removals[0].get_removed_part(), "kan");
+        assert_eq!(removals[0].get_affix_type(), AffixType::DerivationalSuffix);
+        assert_eq!(removals[0].get_visitor_name(), "RemoveDerivationalSuffix");
+        assert_eq!(removals[1].get_removed_part(), "mem");
+        assert_eq!(removals[1].get_affix_type(), AffixType::DerivationalPrefix);
+        assert_eq!(removals[1].get_visitor_name(), "PrefixDisambiguator");
+    }
+
+    #[test]
+    fn should_explain_visitors() {
+        let stemmer = Stemmer::new();
+        let derivation = stemmer.stem_word_explained("wkw");
+        assert_eq!(derivation.resulting_word, "wkw");
+        assert!(derivation.steps.contains(&DerivationStep::Visit {
+            visitor_name: "DontStemShortWord",
+            visitor_type: VisitorType::GeneralVisitor,
+            word: String::from("wkw"),
+            result: VisitorResult::StopProcess,
+        }));
+    }
+
+    #[test]
+    fn should_explain_rule_precedence() {
+        let stemmer = Stemmer::new();
+        let derivation = stemmer.stem_word_explained("membahagiakan");
+        assert!(derivation.steps.contains(&DerivationStep::RulePrecedence(RulePrecedence::SuffixFirst)));
+        assert!(!derivation.steps.contains(&DerivationStep::RulePrecedence(RulePrecedence::PrefixFirst)));
+
+        let derivation = stemmer.stem_word_explained("memaklumi");
+        assert_eq!(derivation.resulting_word, "maklum");
+        assert!(derivation.steps.contains(&DerivationStep::RulePrecedence(RulePrecedence::PrefixFirst)));
+    }
+
+    #[test]
+    fn should_explain_suffix_restoration() {
+        let stemmer = Stemmer::new();
+        let derivation = stemmer.stem_word_explained("berlari");
+        assert_eq!(derivation.resulting_word, "lari");
+        assert!(derivation.steps.contains(&DerivationStep::RestoreSuffix));
+    }
+
+    #[test]
+    fn should_explain_plural_word() {
+        let stemmer = Stemmer::new();
+        let derivation = stemmer.stem_word_explained("bertemu-temu");
+        assert_eq!(derivation.resulting_word, "temu");
+        assert_eq!(derivation.parts.len(), 2);
+        assert_eq!(derivation.parts[0].original_word, "bertemu");
+        assert_eq!(derivation.parts[1].original_word, "temu");
+    }
+
+    #[test]
+    fn should_give_same_result_as_stem() {
+        let stemmer = Stemmer::new();
+        for word in ["menyapu", "pemberdayaan", "tembak-menembak", "kucing-ayam", "penolakan"] {
+            assert_eq!(stemmer.stem_word_explained(word).resulting_word, stemmer.stem(String::from(word)));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_to_json() {
+        let stemmer = Stemmer::new();
+        let derivation = stemmer.stem_word_explained("membaca");
+        let json = serde_json::to_value(&derivation).unwrap();
+        assert_eq!(json["original_word"], "membaca");
+        assert_eq!(json["resulting_word"], "baca");
+        assert!(json["steps"].as_array().unwrap().iter().any(|step| step["Removal"]["removed_part"] == "mem"));
+    }
+}
+
+#[cfg(all(test, feature = "bundled-dictionary"))]
 mod stemmer_test {
     use super::*;
 
@@ -0,0 +1,81 @@
+/// Variant of the stemming algorithm.
+///
+/// Every variant is built from the same visitors, and only differs in
+/// which prefix disambiguation rules are used and which steps are taken:
+///
+/// | Algorithm                          | Prefix rules | Disallowed confixes | Precedence adjustment | Suffix restoration |
+/// |------------------------------------|--------------|---------------------|-----------------------|--------------------|
+/// | `NaziefAdriani`                    | 1 - 33       | yes                 | no                    | no                 |
+/// | `ConfixStripping`                  | 1 - 36       | yes                 | yes                   | no                 |
+/// | `EnhancedConfixStripping`          | 1 - 36       | no                  | yes                   | yes                |
+/// | `ModifiedEnhancedConfixStripping`  | 1 - 41       | no                  | yes                   | yes                |
+///
+/// # Reference
+/// - Nazief B. & Adriani M. (1996) "Confix-stripping: Approach to Stemming Algorithm for Bahasa Indonesia".
+/// - Asian J. (2007) “Effective Techniques for Indonesian Text Retrieval”.
+/// - Arifin A. Z., Mahendra I. P. A. K. & Ciptaningtyas H. T. (2009) "Enhanced Confix Stripping Stemmer and Ants Algorithm for Classifying News Document in Indonesian Language".
+/// - Tahitoe A. D. & Purwitasari D. (2010) "Implementasi Modifikasi Enhanced Confix Stripping Stemmer untuk Bahasa Indonesia dengan Metode Corpus Based Stemming".
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Algorithm {
+    /// Original ordering: suffixes are always removed before prefixes.
+    NaziefAdriani,
+    /// Asian's Confix Stripping: adds rules 34 - 36 and the rule precedence adjustment.
+    ConfixStripping,
+    /// Enhanced Confix Stripping: allows every confix, and adds the suffix restoration loop.
+    EnhancedConfixStripping,
+    /// Modified Enhanced Confix Stripping: adds the infix rules 37 - 40 and rule 41, as in Sastrawi.
+    #[default]
+    ModifiedEnhancedConfixStripping,
+}
+
+impl Algorithm {
+    /// Returns the number of the last prefix disambiguation rule used by the algorithm
+    pub fn last_prefix_rule(&self) -> usize {
+        match self {
+            Algorithm::NaziefAdriani => 33,
+            Algorithm::ConfixStripping | Algorithm::EnhancedConfixStripping => 36,
+            Algorithm::ModifiedEnhancedConfixStripping => 41,
+        }
+    }
+
+    /// Returns true if a prefix can not be removed when it forms a disallowed confix with the removed suffix
+    pub fn checks_disallowed_confixes(&self) -> bool {
+        matches!(self, Algorithm::NaziefAdriani | Algorithm::ConfixStripping)
+    }
+
+    /// Returns true if prefixes are removed first on words satisfying the precedence adjustment
+    pub fn uses_precedence_adjustment(&self) -> bool {
+        !matches!(self, Algorithm::NaziefAdriani)
+    }
+
+    /// Returns true if the removed suffixes are restored when no root word is found
+    pub fn uses_suffix_restoration(&self) -> bool {
+        matches!(self, Algorithm::EnhancedConfixStripping | Algorithm::ModifiedEnhancedConfixStripping)
+    }
+}
+
+#[cfg(test)]
+mod algorithm_test {
+    use super::*;
+
+    #[test]
+    fn should_default_to_modified_enhanced_confix_stripping() {
+        assert_eq!(Algorithm::default(), Algorithm::ModifiedEnhancedConfixStripping);
+    }
+
+    #[test]
+    fn should_extend_previous_algorithm() {
+        let algorithms = [
+            Algorithm::NaziefAdriani,
+            Algorithm::ConfixStripping,
+            Algorithm::EnhancedConfixStripping,
+            Algorithm::ModifiedEnhancedConfixStripping,
+        ];
+        for pair in algorithms.windows(2) {
+            assert_eq!(pair[0].last_prefix_rule() <= pair[1].last_prefix_rule(), true);
+            assert_eq!(pair[0].uses_precedence_adjustment() <= pair[1].uses_precedence_adjustment(), true);
+            assert_eq!(pair[0].uses_suffix_restoration() <= pair[1].uses_suffix_restoration(), true);
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+use crate::error::Result;
+use crate::stemmer::Stemmer;
+
+/// Default number of word kept by `CachedStemmer::from`
+pub const DEFAULT_CAPACITY: usize = 10_000;
+
+/// Hit and miss statistics of a `CachedStemmer`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CacheStatistics {
+    pub hits: u64,
+    pub misses: u64,
+    pub len: usize,
+    pub capacity: usize,
+}
+
+impl CacheStatistics {
+    /// Returns the ratio of lookups answered from the cache, or 0 if nothing is looked up yet
+    pub fn hit_ratio(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / lookups as f64
+    }
+}
+
+/// Bounded least recently used cache of stemmed words.
+///
+/// Every entry remembers the tick of its last access,
+/// and `recency` maps those ticks back to the word so the oldest one can be evicted.
+struct LruCache {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<String, (String, u64)>,
+    recency: BTreeMap<u64, String>,
+    hits: u64,
+    misses: u64,
+}
+
+impl LruCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn get(&mut self, word: &str) -> Option<String> {
+        let tick = self.next_tick();
+        match self.entries.get_mut(word) {
+            Some((root_word, last_access)) => {
+                self.recency.remove(last_access);
+                self.recency.insert(tick, word.to_string());
+                *last_access = tick;
+                self.hits += 1;
+                Some(root_word.clone())
+            },
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn put(&mut self, word: String, root_word: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        let tick = self.next_tick();
+        if let Some((_, last_access)) = self.entries.insert(word.clone(), (root_word, tick)) {
+            self.recency.remove(&last_access);
+        }
+        self.recency.insert(tick, word);
+
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest_word)) = self.recency.pop_first() else { break };
+            self.entries.remove(&oldest_word);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    fn statistics(&self) -> CacheStatistics {
+        CacheStatistics {
+            hits: self.hits,
+            misses: self.misses,
+            len: self.entries.len(),
+            capacity: self.capacity,
+        }
+    }
+}
+
+/// Stemmer that remembers the root word of recently stemmed words.
+///
+/// Words are cached after normalization, so "Membaca" and "membaca" share a single entry.
+/// The cache is guarded by a mutex, so a `CachedStemmer` can be shared across threads,
+/// e.g. behind an `Arc`. The lock is not held while a word is being stemmed.
+pub struct CachedStemmer {
+    stemmer: Stemmer,
+    cache: Mutex<LruCache>,
+}
+
+impl CachedStemmer {
+    /// Wraps the given stemmer with a cache of `DEFAULT_CAPACITY` words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::stemmer::Stemmer;
+    /// use rustrawi::stemmer::cached_stemmer::CachedStemmer;
+    /// let stemmer = CachedStemmer::from(Stemmer::from(Dictionary::from_list(vec!["baca"])));
+    /// assert_eq!(stemmer.stem(String::from("membaca dibaca")), "baca baca");
+    /// assert_eq!(stemmer.statistics().hits, 0);
+    /// assert_eq!(stemmer.stem(String::from("Membaca")), "baca");
+    /// assert_eq!(stemmer.statistics().hits, 1);
+    /// ```
+    pub fn from(stemmer: Stemmer) -> Self {
+        Self::with_capacity(stemmer, DEFAULT_CAPACITY)
+    }
+
+    /// Wraps the given stemmer with a cache of at most `capacity` words.
+    ///
+    /// A capacity of 0 disables the cache.
+    pub fn with_capacity(stemmer: Stemmer, capacity: usize) -> Self {
+        Self {
+            stemmer,
+            cache: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Returns the wrapped stemmer
+    pub fn get_stemmer(&self) -> &Stemmer {
+        &self.stemmer
+    }
+
+    /// Stem the given text, using the cached root word of each word when available.
+    pub fn stem(&self, text: String) -> String {
+        self.stemmer.stem_with(&text, &|word| self.stem_normalized_word(word))
+    }
+
+    /// Stem a single word, or returns an error if the given text is not a single word.
+    pub fn try_stem_word(&self, word: &str) -> Result<String> {
+        self.stemmer.try_stem_word_with(word, &|word| self.stem_normalized_word(word))
+    }
+
+    /// Returns the hit and miss statistics of the cache
+    pub fn statistics(&self) -> CacheStatistics {
+        self.lock_cache().statistics()
+    }
+
+    /// Removes every cached word and resets the statistics
+    pub fn clear(&self) {
+        self.lock_cache().clear();
+    }
+
+    /// Returns the cached root word of the normalized word, or stems it and caches the result
+    fn stem_normalized_word(&self, word: &str) -> String {
+        if let Some(root_word) = self.lock_cache().get(word) {
+            return root_word;
+        }
+        let mut context = self.stemmer.new_context(word);
+        let root_word = self.stemmer.stem_normalized_word(&mut context, word);
+        self.lock_cache().put(word.to_string(), root_word.clone());
+        root_word
+    }
+
+    /// A panic while the lock is held can not leave the cache half updated,
+    /// so a poisoned lock is simply recovered.
+    fn lock_cache(&self) -> MutexGuard<'_, LruCache> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod lru_cache_test {
+    use super::*;
+
+    #[test]
+    fn should_count_hits_and_misses() {
+        let mut cache = LruCache::new(2);
+        assert_eq!(cache.get("membaca"), None);
+        cache.put(String::from("membaca"), String::from("baca"));
+        assert_eq!(cache.get("membaca"), Some(String::from("baca")));
+
+        let statistics = cache.statistics();
+        assert_eq!(statistics.hits, 1);
+        assert_eq!(statistics.misses, 1);
+        assert_eq!(statistics.len, 1);
+        assert_eq!(statistics.hit_ratio(), 0.5);
+    }
+
+    #[test]
+    fn should_evict_least_recently_used_word() {
+        let mut cache = LruCache::new(2);
+        cache.put(String::from("membaca"), String::from("baca"));
+        cache.put(String::from("menulis"), String::from("tulis"));
+        cache.get("membaca");
+        cache.put(String::from("bermain"), String::from("main"));
+
+        assert_eq!(cache.get("menulis"), None);
+        assert_eq!(cache.get("membaca"), Some(String::from("baca")));
+        assert_eq!(cache.get("bermain"), Some(String::from("main")));
+        assert_eq!(cache.statistics().len, 2);
+    }
+
+    #[test]
+    fn should_update_existing_word() {
+        let mut cache = LruCache::new(2);
+        cache.put(String::from("membaca"), String::from("membaca"));
+        cache.put(String::from("membaca"), String::from("baca"));
+        assert_eq!(cache.get("membaca"), Some(String::from("baca")));
+        assert_eq!(cache.statistics().len, 1);
+        assert_eq!(cache.recency.len(), 1);
+    }
+
+    #[test]
+    fn should_not_store_anything_without_capacity() {
+        let mut cache = LruCache::new(0);
+        cache.put(String::from("membaca"), String::from("baca"));
+        assert_eq!(cache.get("membaca"), None);
+        assert_eq!(cache.statistics().len, 0);
+    }
+
+    #[test]
+    fn should_clear() {
+        let mut cache = LruCache::new(2);
+        cache.put(String::from("membaca"), String::from("baca"));
+        cache.get("membaca");
+        cache.clear();
+        assert_eq!(cache.statistics(), CacheStatistics { hits: 0, misses: 0, len: 0, capacity: 2 });
+    }
+}
+
+#[cfg(test)]
+mod cached_stemmer_test {
+    use std::sync::Arc;
+    use std::thread;
+    use super::*;
+    use crate::dictionary::Dictionary;
+    use crate::error::Error;
+    use crate::normalizer::Normalizer;
+    use crate::normalizer::case_policy::CasePolicy;
+
+    fn stemmer(capacity: usize) -> CachedStemmer {
+        CachedStemmer::with_capacity(Stemmer::from(Dictionary::from_list(vec!["baca", "tulis", "kucing"])), capacity)
+    }
+
+    #[test]
+    fn should_stem_like_the_wrapped_stemmer() {
+        let stemmer = stemmer(10);
+        let text = "Membaca, menulis dan kucing-kucing membaca";
+        assert_eq!(stemmer.stem(String::from(text)), stemmer.get_stemmer().stem(String::from(text)));
+    }
+
+    #[test]
+    fn should_keep_urls_as_written() {
+        let normalizer = Normalizer::from(vec![Box::new(CasePolicy::Lowercase)]);
+        let stemmer = CachedStemmer::with_capacity(Stemmer::builder().dictionary(Dictionary::from_list(vec!["baca"])).normalizer(normalizer).build(), 10);
+        assert_eq!(stemmer.stem(String::from("Membaca https://example.com/Buku")), "baca https://example.com/Buku");
+        assert_eq!(stemmer.try_stem_word("https://example.com/Buku").unwrap(), "https://example.com/Buku");
+    }
+
+    #[test]
+    fn should_cache_normalized_word() {
+        let stemmer = stemmer(10);
+        assert_eq!(stemmer.stem(String::from("Membaca membaca MEMBACA!")), "baca baca baca");
+
+        let statistics = stemmer.statistics();
+        assert_eq!(statistics.hits, 2);
+        assert_eq!(statistics.misses, 1);
+        assert_eq!(statistics.len, 1);
+    }
+
+    #[test]
+    fn should_cache_plural_word() {
+        let stemmer = stemmer(10);
+        assert_eq!(stemmer.try_stem_word("kucing-kucing").unwrap(), "kucing");
+        assert_eq!(stemmer.try_stem_word("Kucing-Kucing").unwrap(), "kucing");
+        assert_eq!(stemmer.statistics().hits, 1);
+    }
+
+    #[test]
+    fn should_return_error_on_invalid_word() {
+        let stemmer = stemmer(10);
+        assert_eq!(matches!(stemmer.try_stem_word("membaca buku"), Err(Error::InvalidWord(_))), true);
+        assert_eq!(stemmer.statistics().misses, 0);
+    }
+
+    #[test]
+    fn should_stay_within_capacity() {
+        let stemmer = stemmer(2);
+        stemmer.stem(String::from("membaca menulis dibaca ditulis"));
+        assert_eq!(stemmer.statistics().len, 2);
+    }
+
+    #[test]
+    fn should_still_stem_without_capacity() {
+        let stemmer = stemmer(0);
+        assert_eq!(stemmer.stem(String::from("membaca membaca")), "baca baca");
+        assert_eq!(stemmer.statistics().hits, 0);
+        assert_eq!(stemmer.statistics().misses, 2);
+    }
+
+    #[test]
+    fn should_be_shared_across_threads() {
+        let stemmer = Arc::new(stemmer(10));
+        let handles: Vec<_> = (0..4).map(|_| {
+            let stemmer = Arc::clone(&stemmer);
+            thread::spawn(move || stemmer.stem(String::from("membaca menulis")))
+        }).collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), "baca tulis");
+        }
+        let statistics = stemmer.statistics();
+        assert_eq!(statistics.hits + statistics.misses, 8);
+        assert_eq!(statistics.len, 2);
+    }
+}
@@ -1 +1,2 @@
+pub mod disambiguator;
 pub mod precedence_adjustment;
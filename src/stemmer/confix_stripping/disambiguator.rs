@@ -0,0 +1,54 @@
+pub mod rule_1;
+pub mod rule_2;
+pub mod rule_3;
+pub mod rule_4;
+pub mod rule_5;
+pub mod rule_6;
+pub mod rule_7;
+pub mod rule_8;
+pub mod rule_9;
+pub mod rule_10;
+pub mod rule_11;
+pub mod rule_12;
+pub mod rule_13;
+pub mod rule_14;
+pub mod rule_15;
+pub mod rule_16;
+pub mod rule_17;
+pub mod rule_18;
+pub mod rule_19;
+pub mod rule_20;
+pub mod rule_21;
+pub mod rule_23;
+pub mod rule_24;
+pub mod rule_25;
+pub mod rule_26;
+pub mod rule_27;
+pub mod rule_28;
+pub mod rule_29;
+pub mod rule_30;
+pub mod rule_31;
+pub mod rule_32;
+pub mod rule_33;
+pub mod rule_34;
+pub mod rule_35;
+pub mod rule_36;
+pub mod rule_37;
+pub mod rule_38;
+pub mod rule_39;
+pub mod rule_40;
+pub mod rule_41;
+
+/// Disambiguates a prefixed word into its possible unprefixed form.
+///
+/// Each rule matches a single morphophonemic variant of a derivational prefix,
+/// e.g. "meny-" in "menyapu" becomes "s" in "sapu".
+///
+/// Rule 22 is intentionally absent, following the rule numbering of the original Sastrawi.
+///
+/// # Reference
+/// - Asian J. (2007) “Effective Techniques for Indonesian Text Retrieval” page 61-63.
+/// - Arifin, A.Z., et al. (2009) “Enhanced Confix Stripping Stemmer and Ants Algorithm for Classifying News Document in Indonesian Language”.
+pub trait Disambiguator: Send + Sync {
+    fn disambiguate(&self, word: &str) -> Option<String>;
+}
@@ -0,0 +1,84 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 1a: berV -> ber-V
+pub struct Rule1a {
+    regex: Regex,
+}
+
+impl Rule1a {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^ber([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule1a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule1a {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+/// Disambiguator prefix rule 1b: berV -> be-rV
+pub struct Rule1b {
+    regex: Regex,
+}
+
+impl Rule1b {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^ber([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule1b {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule1b {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("r{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_1_test {
+    use super::*;
+
+    #[test]
+    fn rule_1a_should_disambiguate() {
+        let rule = Rule1a::new();
+        assert_eq!(rule.disambiguate("berapi"), Some(String::from("api")));
+        assert_eq!(rule.disambiguate("beranak"), Some(String::from("anak")));
+    }
+
+    #[test]
+    fn rule_1a_should_ignore_other_word() {
+        let rule = Rule1a::new();
+        assert_eq!(rule.disambiguate("bertemu"), None);
+    }
+
+    #[test]
+    fn rule_1b_should_disambiguate() {
+        let rule = Rule1b::new();
+        assert_eq!(rule.disambiguate("berapi"), Some(String::from("rapi")));
+    }
+
+    #[test]
+    fn rule_1b_should_ignore_other_word() {
+        let rule = Rule1b::new();
+        assert_eq!(rule.disambiguate("bertemu"), None);
+    }
+}
@@ -0,0 +1,48 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 10: me{l|r|w|y}V -> me-{l|r|w|y}V
+pub struct Rule10 {
+    regex: Regex,
+}
+
+impl Rule10 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^me([lrwy][aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule10 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule10 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod rule_10_test {
+    use super::*;
+
+    #[test]
+    fn rule_10_should_disambiguate() {
+        let rule = Rule10::new();
+        assert_eq!(rule.disambiguate("melipat"), Some(String::from("lipat")));
+        assert_eq!(rule.disambiguate("merusak"), Some(String::from("rusak")));
+        assert_eq!(rule.disambiguate("mewarnai"), Some(String::from("warnai")));
+        assert_eq!(rule.disambiguate("meyakinkan"), Some(String::from("yakinkan")));
+    }
+
+    #[test]
+    fn rule_10_should_ignore_other_word() {
+        let rule = Rule10::new();
+        assert_eq!(rule.disambiguate("membaca"), None);
+    }
+}
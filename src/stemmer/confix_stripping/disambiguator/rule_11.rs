@@ -0,0 +1,47 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 11: mem{b|f|v} -> mem-{b|f|v}
+pub struct Rule11 {
+    regex: Regex,
+}
+
+impl Rule11 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^mem([bfv].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule11 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule11 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod rule_11_test {
+    use super::*;
+
+    #[test]
+    fn rule_11_should_disambiguate() {
+        let rule = Rule11::new();
+        assert_eq!(rule.disambiguate("membaca"), Some(String::from("baca")));
+        assert_eq!(rule.disambiguate("memfitnah"), Some(String::from("fitnah")));
+        assert_eq!(rule.disambiguate("memvonis"), Some(String::from("vonis")));
+    }
+
+    #[test]
+    fn rule_11_should_ignore_other_word() {
+        let rule = Rule11::new();
+        assert_eq!(rule.disambiguate("memakan"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 12: mempe -> mem-pe
+pub struct Rule12 {
+    regex: Regex,
+}
+
+impl Rule12 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^mempe(.*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule12 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule12 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("pe{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_12_test {
+    use super::*;
+
+    #[test]
+    fn rule_12_should_disambiguate() {
+        let rule = Rule12::new();
+        assert_eq!(rule.disambiguate("memperbaiki"), Some(String::from("perbaiki")));
+        assert_eq!(rule.disambiguate("mempelajari"), Some(String::from("pelajari")));
+    }
+
+    #[test]
+    fn rule_12_should_ignore_other_word() {
+        let rule = Rule12::new();
+        assert_eq!(rule.disambiguate("mempunyai"), None);
+    }
+}
@@ -0,0 +1,85 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 13a: mem{rV|V} -> me-m{rV|V}
+pub struct Rule13a {
+    regex: Regex,
+}
+
+impl Rule13a {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^mem(r?[aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule13a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule13a {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("m{}", &captures[1]))
+    }
+}
+
+/// Disambiguator prefix rule 13b: mem{rV|V} -> me-p{rV|V}
+pub struct Rule13b {
+    regex: Regex,
+}
+
+impl Rule13b {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^mem(r?[aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule13b {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule13b {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("p{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_13_test {
+    use super::*;
+
+    #[test]
+    fn rule_13a_should_disambiguate() {
+        let rule = Rule13a::new();
+        assert_eq!(rule.disambiguate("memakan"), Some(String::from("makan")));
+    }
+
+    #[test]
+    fn rule_13a_should_ignore_other_word() {
+        let rule = Rule13a::new();
+        assert_eq!(rule.disambiguate("membaca"), None);
+    }
+
+    #[test]
+    fn rule_13b_should_disambiguate() {
+        let rule = Rule13b::new();
+        assert_eq!(rule.disambiguate("memakan"), Some(String::from("pakan")));
+        assert_eq!(rule.disambiguate("memukul"), Some(String::from("pukul")));
+        assert_eq!(rule.disambiguate("memroses"), Some(String::from("proses")));
+    }
+
+    #[test]
+    fn rule_13b_should_ignore_other_word() {
+        let rule = Rule13b::new();
+        assert_eq!(rule.disambiguate("membaca"), None);
+    }
+}
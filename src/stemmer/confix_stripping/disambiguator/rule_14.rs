@@ -0,0 +1,48 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 14: men{c|d|j|s|t|z} -> men-{c|d|j|s|t|z}
+pub struct Rule14 {
+    regex: Regex,
+}
+
+impl Rule14 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^men([cdjstz].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule14 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule14 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod rule_14_test {
+    use super::*;
+
+    #[test]
+    fn rule_14_should_disambiguate() {
+        let rule = Rule14::new();
+        assert_eq!(rule.disambiguate("mencantumkan"), Some(String::from("cantumkan")));
+        assert_eq!(rule.disambiguate("mendua"), Some(String::from("dua")));
+        assert_eq!(rule.disambiguate("menjemput"), Some(String::from("jemput")));
+        assert_eq!(rule.disambiguate("menziarahi"), Some(String::from("ziarahi")));
+    }
+
+    #[test]
+    fn rule_14_should_ignore_other_word() {
+        let rule = Rule14::new();
+        assert_eq!(rule.disambiguate("menilai"), None);
+    }
+}
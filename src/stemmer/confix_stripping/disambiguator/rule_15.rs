@@ -0,0 +1,84 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 15a: menV -> me-nV
+pub struct Rule15a {
+    regex: Regex,
+}
+
+impl Rule15a {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^men([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule15a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule15a {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("n{}", &captures[1]))
+    }
+}
+
+/// Disambiguator prefix rule 15b: menV -> me-tV
+pub struct Rule15b {
+    regex: Regex,
+}
+
+impl Rule15b {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^men([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule15b {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule15b {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("t{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_15_test {
+    use super::*;
+
+    #[test]
+    fn rule_15a_should_disambiguate() {
+        let rule = Rule15a::new();
+        assert_eq!(rule.disambiguate("menilai"), Some(String::from("nilai")));
+    }
+
+    #[test]
+    fn rule_15a_should_ignore_other_word() {
+        let rule = Rule15a::new();
+        assert_eq!(rule.disambiguate("mendua"), None);
+    }
+
+    #[test]
+    fn rule_15b_should_disambiguate() {
+        let rule = Rule15b::new();
+        assert_eq!(rule.disambiguate("menilai"), Some(String::from("tilai")));
+        assert_eq!(rule.disambiguate("menanam"), Some(String::from("tanam")));
+    }
+
+    #[test]
+    fn rule_15b_should_ignore_other_word() {
+        let rule = Rule15b::new();
+        assert_eq!(rule.disambiguate("mendua"), None);
+    }
+}
@@ -0,0 +1,48 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 16: meng{g|h|q|k} -> meng-{g|h|q|k}
+pub struct Rule16 {
+    regex: Regex,
+}
+
+impl Rule16 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^meng([ghqk].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule16 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule16 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod rule_16_test {
+    use super::*;
+
+    #[test]
+    fn rule_16_should_disambiguate() {
+        let rule = Rule16::new();
+        assert_eq!(rule.disambiguate("menggunakan"), Some(String::from("gunakan")));
+        assert_eq!(rule.disambiguate("menghajar"), Some(String::from("hajar")));
+        assert_eq!(rule.disambiguate("mengqasar"), Some(String::from("qasar")));
+        assert_eq!(rule.disambiguate("mengkritik"), Some(String::from("kritik")));
+    }
+
+    #[test]
+    fn rule_16_should_ignore_other_word() {
+        let rule = Rule16::new();
+        assert_eq!(rule.disambiguate("mengambil"), None);
+    }
+}
@@ -0,0 +1,159 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 17a: mengV -> meng-V
+pub struct Rule17a {
+    regex: Regex,
+}
+
+impl Rule17a {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^meng([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule17a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule17a {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+/// Disambiguator prefix rule 17b: mengV -> meng-kV
+pub struct Rule17b {
+    regex: Regex,
+}
+
+impl Rule17b {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^meng([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule17b {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule17b {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("k{}", &captures[1]))
+    }
+}
+
+/// Disambiguator prefix rule 17c: mengV -> mengV- where V = 'e'
+pub struct Rule17c {
+    regex: Regex,
+}
+
+impl Rule17c {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^menge(.*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule17c {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule17c {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+/// Disambiguator prefix rule 17d: mengV -> me-ngV
+pub struct Rule17d {
+    regex: Regex,
+}
+
+impl Rule17d {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^meng([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule17d {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule17d {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("ng{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_17_test {
+    use super::*;
+
+    #[test]
+    fn rule_17a_should_disambiguate() {
+        let rule = Rule17a::new();
+        assert_eq!(rule.disambiguate("mengambil"), Some(String::from("ambil")));
+    }
+
+    #[test]
+    fn rule_17a_should_ignore_other_word() {
+        let rule = Rule17a::new();
+        assert_eq!(rule.disambiguate("menggunakan"), None);
+    }
+
+    #[test]
+    fn rule_17b_should_disambiguate() {
+        let rule = Rule17b::new();
+        assert_eq!(rule.disambiguate("mengupas"), Some(String::from("kupas")));
+    }
+
+    #[test]
+    fn rule_17b_should_ignore_other_word() {
+        let rule = Rule17b::new();
+        assert_eq!(rule.disambiguate("menggunakan"), None);
+    }
+
+    #[test]
+    fn rule_17c_should_disambiguate() {
+        let rule = Rule17c::new();
+        assert_eq!(rule.disambiguate("mengebom"), Some(String::from("bom")));
+    }
+
+    #[test]
+    fn rule_17c_should_ignore_other_word() {
+        let rule = Rule17c::new();
+        assert_eq!(rule.disambiguate("mengambil"), None);
+    }
+
+    #[test]
+    fn rule_17d_should_disambiguate() {
+        let rule = Rule17d::new();
+        assert_eq!(rule.disambiguate("menganga"), Some(String::from("nganga")));
+    }
+
+    #[test]
+    fn rule_17d_should_ignore_other_word() {
+        let rule = Rule17d::new();
+        assert_eq!(rule.disambiguate("menggunakan"), None);
+    }
+}
@@ -0,0 +1,83 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 18a: menyV -> me-nyV
+pub struct Rule18a {
+    regex: Regex,
+}
+
+impl Rule18a {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^meny([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule18a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule18a {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("ny{}", &captures[1]))
+    }
+}
+
+/// Disambiguator prefix rule 18b: menyV -> meny-sV
+pub struct Rule18b {
+    regex: Regex,
+}
+
+impl Rule18b {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^meny([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule18b {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule18b {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("s{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_18_test {
+    use super::*;
+
+    #[test]
+    fn rule_18a_should_disambiguate() {
+        let rule = Rule18a::new();
+        assert_eq!(rule.disambiguate("menyanyi"), Some(String::from("nyanyi")));
+    }
+
+    #[test]
+    fn rule_18a_should_ignore_other_word() {
+        let rule = Rule18a::new();
+        assert_eq!(rule.disambiguate("menilai"), None);
+    }
+
+    #[test]
+    fn rule_18b_should_disambiguate() {
+        let rule = Rule18b::new();
+        assert_eq!(rule.disambiguate("menyapu"), Some(String::from("sapu")));
+    }
+
+    #[test]
+    fn rule_18b_should_ignore_other_word() {
+        let rule = Rule18b::new();
+        assert_eq!(rule.disambiguate("menilai"), None);
+    }
+}
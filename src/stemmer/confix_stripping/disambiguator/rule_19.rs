@@ -0,0 +1,46 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 19: mempV -> mem-pV where V != 'e'
+pub struct Rule19 {
+    regex: Regex,
+}
+
+impl Rule19 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^memp([abcdfghijklmnopqrstuvwxyz].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule19 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule19 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("p{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_19_test {
+    use super::*;
+
+    #[test]
+    fn rule_19_should_disambiguate() {
+        let rule = Rule19::new();
+        assert_eq!(rule.disambiguate("mempunyai"), Some(String::from("punyai")));
+    }
+
+    #[test]
+    fn rule_19_should_ignore_other_word() {
+        let rule = Rule19::new();
+        assert_eq!(rule.disambiguate("mempengaruhi"), None);
+        assert_eq!(rule.disambiguate("membaca"), None);
+    }
+}
@@ -0,0 +1,55 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 2: berCAP -> ber-CAP where C != 'r' and P != 'er'
+///
+/// As in the reference implementation of Sastrawi, C may still be 'r', e.g. "berrambut" -> "rambut",
+/// since rule 1 only matches "ber" followed by a vowel.
+pub struct Rule2 {
+    regex: Regex,
+}
+
+impl Rule2 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^ber([bcdfghjklmnpqrstvwxyz])([a-z])(.*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule2 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule2 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        if captures[3].starts_with("er") {
+            return None;
+        }
+        Some(format!("{}{}{}", &captures[1], &captures[2], &captures[3]))
+    }
+}
+
+#[cfg(test)]
+mod rule_2_test {
+    use super::*;
+
+    #[test]
+    fn rule_2_should_disambiguate() {
+        let rule = Rule2::new();
+        assert_eq!(rule.disambiguate("bertemu"), Some(String::from("temu")));
+        assert_eq!(rule.disambiguate("bermain"), Some(String::from("main")));
+        assert_eq!(rule.disambiguate("berterbangan"), Some(String::from("terbangan")));
+        assert_eq!(rule.disambiguate("berrambut"), Some(String::from("rambut")));
+    }
+
+    #[test]
+    fn rule_2_should_ignore_other_word() {
+        let rule = Rule2::new();
+        assert_eq!(rule.disambiguate("bertaerap"), None);
+        assert_eq!(rule.disambiguate("beradu"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 20: pe{w|y}V -> pe-{w|y}V
+pub struct Rule20 {
+    regex: Regex,
+}
+
+impl Rule20 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^pe([wy][aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule20 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule20 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod rule_20_test {
+    use super::*;
+
+    #[test]
+    fn rule_20_should_disambiguate() {
+        let rule = Rule20::new();
+        assert_eq!(rule.disambiguate("pewarna"), Some(String::from("warna")));
+        assert_eq!(rule.disambiguate("peyoga"), Some(String::from("yoga")));
+    }
+
+    #[test]
+    fn rule_20_should_ignore_other_word() {
+        let rule = Rule20::new();
+        assert_eq!(rule.disambiguate("pelaku"), None);
+    }
+}
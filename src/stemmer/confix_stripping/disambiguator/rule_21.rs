@@ -0,0 +1,83 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 21a: perV -> per-V
+pub struct Rule21a {
+    regex: Regex,
+}
+
+impl Rule21a {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^per([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule21a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule21a {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+/// Disambiguator prefix rule 21b: perV -> pe-rV
+pub struct Rule21b {
+    regex: Regex,
+}
+
+impl Rule21b {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^per([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule21b {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule21b {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("r{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_21_test {
+    use super::*;
+
+    #[test]
+    fn rule_21a_should_disambiguate() {
+        let rule = Rule21a::new();
+        assert_eq!(rule.disambiguate("peradilan"), Some(String::from("adilan")));
+    }
+
+    #[test]
+    fn rule_21a_should_ignore_other_word() {
+        let rule = Rule21a::new();
+        assert_eq!(rule.disambiguate("perbaiki"), None);
+    }
+
+    #[test]
+    fn rule_21b_should_disambiguate() {
+        let rule = Rule21b::new();
+        assert_eq!(rule.disambiguate("perusak"), Some(String::from("rusak")));
+    }
+
+    #[test]
+    fn rule_21b_should_ignore_other_word() {
+        let rule = Rule21b::new();
+        assert_eq!(rule.disambiguate("perbaiki"), None);
+    }
+}
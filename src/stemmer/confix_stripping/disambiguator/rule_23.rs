@@ -0,0 +1,50 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 23: perCAP -> per-CAP where C != 'r' and P != 'er'
+pub struct Rule23 {
+    regex: Regex,
+}
+
+impl Rule23 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^per([bcdfghjklmnpqstvwxyz])([a-z])(.*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule23 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule23 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        if captures[3].starts_with("er") {
+            return None;
+        }
+        Some(format!("{}{}{}", &captures[1], &captures[2], &captures[3]))
+    }
+}
+
+#[cfg(test)]
+mod rule_23_test {
+    use super::*;
+
+    #[test]
+    fn rule_23_should_disambiguate() {
+        let rule = Rule23::new();
+        assert_eq!(rule.disambiguate("perbaiki"), Some(String::from("baiki")));
+        assert_eq!(rule.disambiguate("perdalam"), Some(String::from("dalam")));
+    }
+
+    #[test]
+    fn rule_23_should_ignore_other_word() {
+        let rule = Rule23::new();
+        assert_eq!(rule.disambiguate("perdaerah"), None);
+        assert_eq!(rule.disambiguate("perrusak"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 24: perCAerV -> per-CAerV where C != 'r'
+pub struct Rule24 {
+    regex: Regex,
+}
+
+impl Rule24 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^per([bcdfghjklmnpqstvwxyz])([a-z])er([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule24 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule24 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}{}er{}", &captures[1], &captures[2], &captures[3]))
+    }
+}
+
+#[cfg(test)]
+mod rule_24_test {
+    use super::*;
+
+    #[test]
+    fn rule_24_should_disambiguate() {
+        let rule = Rule24::new();
+        assert_eq!(rule.disambiguate("perdaerah"), Some(String::from("daerah")));
+    }
+
+    #[test]
+    fn rule_24_should_ignore_other_word() {
+        let rule = Rule24::new();
+        assert_eq!(rule.disambiguate("perraerah"), None);
+        assert_eq!(rule.disambiguate("perbaiki"), None);
+    }
+}
@@ -0,0 +1,47 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 25: pem{b|f|v} -> pem-{b|f|v}
+pub struct Rule25 {
+    regex: Regex,
+}
+
+impl Rule25 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^pem([bfv].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule25 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule25 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod rule_25_test {
+    use super::*;
+
+    #[test]
+    fn rule_25_should_disambiguate() {
+        let rule = Rule25::new();
+        assert_eq!(rule.disambiguate("pembaca"), Some(String::from("baca")));
+        assert_eq!(rule.disambiguate("pemfitnah"), Some(String::from("fitnah")));
+        assert_eq!(rule.disambiguate("pemvonis"), Some(String::from("vonis")));
+    }
+
+    #[test]
+    fn rule_25_should_ignore_other_word() {
+        let rule = Rule25::new();
+        assert_eq!(rule.disambiguate("pemakan"), None);
+    }
+}
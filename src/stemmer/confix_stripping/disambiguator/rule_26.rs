@@ -0,0 +1,84 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 26a: pem{rV|V} -> pe-m{rV|V}
+pub struct Rule26a {
+    regex: Regex,
+}
+
+impl Rule26a {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^pem(r?[aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule26a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule26a {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("m{}", &captures[1]))
+    }
+}
+
+/// Disambiguator prefix rule 26b: pem{rV|V} -> pe-p{rV|V}
+pub struct Rule26b {
+    regex: Regex,
+}
+
+impl Rule26b {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^pem(r?[aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule26b {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule26b {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("p{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_26_test {
+    use super::*;
+
+    #[test]
+    fn rule_26a_should_disambiguate() {
+        let rule = Rule26a::new();
+        assert_eq!(rule.disambiguate("pemakan"), Some(String::from("makan")));
+    }
+
+    #[test]
+    fn rule_26a_should_ignore_other_word() {
+        let rule = Rule26a::new();
+        assert_eq!(rule.disambiguate("pembaca"), None);
+    }
+
+    #[test]
+    fn rule_26b_should_disambiguate() {
+        let rule = Rule26b::new();
+        assert_eq!(rule.disambiguate("pemukul"), Some(String::from("pukul")));
+        assert_eq!(rule.disambiguate("pemrogram"), Some(String::from("program")));
+    }
+
+    #[test]
+    fn rule_26b_should_ignore_other_word() {
+        let rule = Rule26b::new();
+        assert_eq!(rule.disambiguate("pembaca"), None);
+    }
+}
@@ -0,0 +1,48 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 27: pen{c|d|j|s|t|z} -> pen-{c|d|j|s|t|z}
+pub struct Rule27 {
+    regex: Regex,
+}
+
+impl Rule27 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^pen([cdjstz].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule27 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule27 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod rule_27_test {
+    use super::*;
+
+    #[test]
+    fn rule_27_should_disambiguate() {
+        let rule = Rule27::new();
+        assert_eq!(rule.disambiguate("pencari"), Some(String::from("cari")));
+        assert_eq!(rule.disambiguate("pendaki"), Some(String::from("daki")));
+        assert_eq!(rule.disambiguate("penjual"), Some(String::from("jual")));
+        assert_eq!(rule.disambiguate("penzina"), Some(String::from("zina")));
+    }
+
+    #[test]
+    fn rule_27_should_ignore_other_word() {
+        let rule = Rule27::new();
+        assert_eq!(rule.disambiguate("penulis"), None);
+    }
+}
@@ -0,0 +1,83 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 28a: penV -> pe-nV
+pub struct Rule28a {
+    regex: Regex,
+}
+
+impl Rule28a {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^pen([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule28a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule28a {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("n{}", &captures[1]))
+    }
+}
+
+/// Disambiguator prefix rule 28b: penV -> pe-tV
+pub struct Rule28b {
+    regex: Regex,
+}
+
+impl Rule28b {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^pen([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule28b {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule28b {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("t{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_28_test {
+    use super::*;
+
+    #[test]
+    fn rule_28a_should_disambiguate() {
+        let rule = Rule28a::new();
+        assert_eq!(rule.disambiguate("penasihat"), Some(String::from("nasihat")));
+    }
+
+    #[test]
+    fn rule_28a_should_ignore_other_word() {
+        let rule = Rule28a::new();
+        assert_eq!(rule.disambiguate("pencari"), None);
+    }
+
+    #[test]
+    fn rule_28b_should_disambiguate() {
+        let rule = Rule28b::new();
+        assert_eq!(rule.disambiguate("penulis"), Some(String::from("tulis")));
+    }
+
+    #[test]
+    fn rule_28b_should_ignore_other_word() {
+        let rule = Rule28b::new();
+        assert_eq!(rule.disambiguate("pencari"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 29: pengC -> peng-C
+pub struct Rule29 {
+    regex: Regex,
+}
+
+impl Rule29 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^peng([bcdfghjklmnpqrstvwxyz].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule29 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule29 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod rule_29_test {
+    use super::*;
+
+    #[test]
+    fn rule_29_should_disambiguate() {
+        let rule = Rule29::new();
+        assert_eq!(rule.disambiguate("penggila"), Some(String::from("gila")));
+        assert_eq!(rule.disambiguate("penghasil"), Some(String::from("hasil")));
+    }
+
+    #[test]
+    fn rule_29_should_ignore_other_word() {
+        let rule = Rule29::new();
+        assert_eq!(rule.disambiguate("pengambil"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 3: berCAerV -> ber-CAerV where C != 'r'
+pub struct Rule3 {
+    regex: Regex,
+}
+
+impl Rule3 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^ber([bcdfghjklmnpqstvwxyz])([a-z])er([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule3 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule3 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}{}er{}", &captures[1], &captures[2], &captures[3]))
+    }
+}
+
+#[cfg(test)]
+mod rule_3_test {
+    use super::*;
+
+    #[test]
+    fn rule_3_should_disambiguate() {
+        let rule = Rule3::new();
+        assert_eq!(rule.disambiguate("bertaerap"), Some(String::from("taerap")));
+    }
+
+    #[test]
+    fn rule_3_should_ignore_other_word() {
+        let rule = Rule3::new();
+        assert_eq!(rule.disambiguate("berraerap"), None);
+        assert_eq!(rule.disambiguate("bertemu"), None);
+    }
+}
@@ -0,0 +1,121 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 30a: pengV -> peng-V
+pub struct Rule30a {
+    regex: Regex,
+}
+
+impl Rule30a {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^peng([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule30a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule30a {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+/// Disambiguator prefix rule 30b: pengV -> peng-kV
+pub struct Rule30b {
+    regex: Regex,
+}
+
+impl Rule30b {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^peng([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule30b {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule30b {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("k{}", &captures[1]))
+    }
+}
+
+/// Disambiguator prefix rule 30c: pengV -> pengV- where V = 'e'
+pub struct Rule30c {
+    regex: Regex,
+}
+
+impl Rule30c {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^penge(.*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule30c {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule30c {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod rule_30_test {
+    use super::*;
+
+    #[test]
+    fn rule_30a_should_disambiguate() {
+        let rule = Rule30a::new();
+        assert_eq!(rule.disambiguate("pengambil"), Some(String::from("ambil")));
+    }
+
+    #[test]
+    fn rule_30a_should_ignore_other_word() {
+        let rule = Rule30a::new();
+        assert_eq!(rule.disambiguate("penggila"), None);
+    }
+
+    #[test]
+    fn rule_30b_should_disambiguate() {
+        let rule = Rule30b::new();
+        assert_eq!(rule.disambiguate("pengupas"), Some(String::from("kupas")));
+    }
+
+    #[test]
+    fn rule_30b_should_ignore_other_word() {
+        let rule = Rule30b::new();
+        assert_eq!(rule.disambiguate("penggila"), None);
+    }
+
+    #[test]
+    fn rule_30c_should_disambiguate() {
+        let rule = Rule30c::new();
+        assert_eq!(rule.disambiguate("pengebom"), Some(String::from("bom")));
+    }
+
+    #[test]
+    fn rule_30c_should_ignore_other_word() {
+        let rule = Rule30c::new();
+        assert_eq!(rule.disambiguate("pengambil"), None);
+    }
+}
@@ -0,0 +1,83 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 31a: penyV -> pe-nyV
+pub struct Rule31a {
+    regex: Regex,
+}
+
+impl Rule31a {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^peny([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule31a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule31a {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("ny{}", &captures[1]))
+    }
+}
+
+/// Disambiguator prefix rule 31b: penyV -> peny-sV
+pub struct Rule31b {
+    regex: Regex,
+}
+
+impl Rule31b {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^peny([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule31b {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule31b {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("s{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_31_test {
+    use super::*;
+
+    #[test]
+    fn rule_31a_should_disambiguate() {
+        let rule = Rule31a::new();
+        assert_eq!(rule.disambiguate("penyanyi"), Some(String::from("nyanyi")));
+    }
+
+    #[test]
+    fn rule_31a_should_ignore_other_word() {
+        let rule = Rule31a::new();
+        assert_eq!(rule.disambiguate("penulis"), None);
+    }
+
+    #[test]
+    fn rule_31b_should_disambiguate() {
+        let rule = Rule31b::new();
+        assert_eq!(rule.disambiguate("penyapu"), Some(String::from("sapu")));
+    }
+
+    #[test]
+    fn rule_31b_should_ignore_other_word() {
+        let rule = Rule31b::new();
+        assert_eq!(rule.disambiguate("penulis"), None);
+    }
+}
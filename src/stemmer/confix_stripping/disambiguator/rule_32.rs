@@ -0,0 +1,49 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 32: pelV -> pe-lV except pelajar -> ajar
+pub struct Rule32 {
+    regex: Regex,
+}
+
+impl Rule32 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^pel([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule32 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        if word == "pelajar" {
+            return Some(String::from("ajar"));
+        }
+        let captures = self.regex.captures(word)?;
+        Some(format!("l{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_32_test {
+    use super::*;
+
+    #[test]
+    fn rule_32_should_disambiguate() {
+        let rule = Rule32::new();
+        assert_eq!(rule.disambiguate("pelaku"), Some(String::from("laku")));
+        assert_eq!(rule.disambiguate("pelajar"), Some(String::from("ajar")));
+    }
+
+    #[test]
+    fn rule_32_should_ignore_other_word() {
+        let rule = Rule32::new();
+        assert_eq!(rule.disambiguate("pembaca"), None);
+    }
+}
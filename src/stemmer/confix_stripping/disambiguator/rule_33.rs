@@ -0,0 +1,47 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 33: peCerV -> pe-CerV where C != {r|w|y|l|m|n}
+pub struct Rule33 {
+    regex: Regex,
+}
+
+impl Rule33 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^pe([bcdfghjkpqstvxz])er([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule33 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule33 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}er{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_33_test {
+    use super::*;
+
+    #[test]
+    fn rule_33_should_disambiguate() {
+        let rule = Rule33::new();
+        assert_eq!(rule.disambiguate("pegerak"), Some(String::from("gerak")));
+        assert_eq!(rule.disambiguate("peterapi"), Some(String::from("terapi")));
+    }
+
+    #[test]
+    fn rule_33_should_ignore_other_word() {
+        let rule = Rule33::new();
+        assert_eq!(rule.disambiguate("pemerah"), None);
+        assert_eq!(rule.disambiguate("pekerja"), None);
+    }
+}
@@ -0,0 +1,49 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 34: peCP -> pe-CP where C != {r|w|y|l|m|n} and P != 'er'
+pub struct Rule34 {
+    regex: Regex,
+}
+
+impl Rule34 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^pe([bcdfghjkpqstvxz])(.*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule34 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule34 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        if captures[2].starts_with("er") {
+            return None;
+        }
+        Some(format!("{}{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_34_test {
+    use super::*;
+
+    #[test]
+    fn rule_34_should_disambiguate() {
+        let rule = Rule34::new();
+        assert_eq!(rule.disambiguate("petani"), Some(String::from("tani")));
+    }
+
+    #[test]
+    fn rule_34_should_ignore_other_word() {
+        let rule = Rule34::new();
+        assert_eq!(rule.disambiguate("pekerja"), None);
+        assert_eq!(rule.disambiguate("pelaku"), None);
+    }
+}
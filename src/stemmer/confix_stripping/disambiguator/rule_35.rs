@@ -0,0 +1,46 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 35: terC1erC2 -> ter-C1erC2 where C1 != 'r'
+pub struct Rule35 {
+    regex: Regex,
+}
+
+impl Rule35 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^ter([bcdfghjklmnpqstvwxyz])(er[bcdfghjklmnpqrstvwxyz].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule35 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule35 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_35_test {
+    use super::*;
+
+    #[test]
+    fn rule_35_should_disambiguate() {
+        let rule = Rule35::new();
+        assert_eq!(rule.disambiguate("terpercaya"), Some(String::from("percaya")));
+    }
+
+    #[test]
+    fn rule_35_should_ignore_other_word() {
+        let rule = Rule35::new();
+        assert_eq!(rule.disambiguate("terrercaya"), None);
+        assert_eq!(rule.disambiguate("tertangkap"), None);
+    }
+}
@@ -0,0 +1,47 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 36: peC1erC2 -> pe-C1erC2 where C1 != {r|w|y|l|m|n}
+pub struct Rule36 {
+    regex: Regex,
+}
+
+impl Rule36 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^pe([bcdfghjkpqstvxz])(er[bcdfghjklmnpqrstvwxyz].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule36 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule36 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_36_test {
+    use super::*;
+
+    #[test]
+    fn rule_36_should_disambiguate() {
+        let rule = Rule36::new();
+        assert_eq!(rule.disambiguate("pekerja"), Some(String::from("kerja")));
+        assert_eq!(rule.disambiguate("peserta"), Some(String::from("serta")));
+    }
+
+    #[test]
+    fn rule_36_should_ignore_other_word() {
+        let rule = Rule36::new();
+        assert_eq!(rule.disambiguate("pemerlu"), None);
+        assert_eq!(rule.disambiguate("petani"), None);
+    }
+}
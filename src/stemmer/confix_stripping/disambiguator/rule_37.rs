@@ -0,0 +1,46 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 37: CerV -> C-erV
+pub struct Rule37 {
+    regex: Regex,
+}
+
+impl Rule37 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^([bcdfghjklmnpqrstvwxyz])er([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule37 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule37 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_37_test {
+    use super::*;
+
+    #[test]
+    fn rule_37_should_disambiguate() {
+        let rule = Rule37::new();
+        assert_eq!(rule.disambiguate("gerigi"), Some(String::from("gigi")));
+        assert_eq!(rule.disambiguate("seruling"), Some(String::from("suling")));
+    }
+
+    #[test]
+    fn rule_37_should_ignore_other_word() {
+        let rule = Rule37::new();
+        assert_eq!(rule.disambiguate("gemuruh"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 38: CelV -> C-elV
+pub struct Rule38 {
+    regex: Regex,
+}
+
+impl Rule38 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^([bcdfghjklmnpqrstvwxyz])el([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule38 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule38 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_38_test {
+    use super::*;
+
+    #[test]
+    fn rule_38_should_disambiguate() {
+        let rule = Rule38::new();
+        assert_eq!(rule.disambiguate("geletar"), Some(String::from("getar")));
+        assert_eq!(rule.disambiguate("telunjuk"), Some(String::from("tunjuk")));
+    }
+
+    #[test]
+    fn rule_38_should_ignore_other_word() {
+        let rule = Rule38::new();
+        assert_eq!(rule.disambiguate("gerigi"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 39: CemV -> C-emV
+pub struct Rule39 {
+    regex: Regex,
+}
+
+impl Rule39 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^([bcdfghjklmnpqrstvwxyz])em([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule39 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule39 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_39_test {
+    use super::*;
+
+    #[test]
+    fn rule_39_should_disambiguate() {
+        let rule = Rule39::new();
+        assert_eq!(rule.disambiguate("gemuruh"), Some(String::from("guruh")));
+        assert_eq!(rule.disambiguate("kemilau"), Some(String::from("kilau")));
+    }
+
+    #[test]
+    fn rule_39_should_ignore_other_word() {
+        let rule = Rule39::new();
+        assert_eq!(rule.disambiguate("gerigi"), None);
+    }
+}
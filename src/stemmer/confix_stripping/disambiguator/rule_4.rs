@@ -0,0 +1,48 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 4: belajar -> bel-ajar
+pub struct Rule4 {
+    regex: Regex,
+}
+
+impl Rule4 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^belajar$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule4 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule4 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        if !self.regex.is_match(word) {
+            return None;
+        }
+        Some(String::from("ajar"))
+    }
+}
+
+#[cfg(test)]
+mod rule_4_test {
+    use super::*;
+
+    #[test]
+    fn rule_4_should_disambiguate() {
+        let rule = Rule4::new();
+        assert_eq!(rule.disambiguate("belajar"), Some(String::from("ajar")));
+    }
+
+    #[test]
+    fn rule_4_should_ignore_other_word() {
+        let rule = Rule4::new();
+        assert_eq!(rule.disambiguate("belajarlah"), None);
+        assert_eq!(rule.disambiguate("bekerja"), None);
+    }
+}
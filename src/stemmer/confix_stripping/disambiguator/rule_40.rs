@@ -0,0 +1,46 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 40: CinV -> C-inV
+pub struct Rule40 {
+    regex: Regex,
+}
+
+impl Rule40 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^([bcdfghjklmnpqrstvwxyz])in([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule40 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule40 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_40_test {
+    use super::*;
+
+    #[test]
+    fn rule_40_should_disambiguate() {
+        let rule = Rule40::new();
+        assert_eq!(rule.disambiguate("sinambung"), Some(String::from("sambung")));
+        assert_eq!(rule.disambiguate("kinerja"), Some(String::from("kerja")));
+    }
+
+    #[test]
+    fn rule_40_should_ignore_other_word() {
+        let rule = Rule40::new();
+        assert_eq!(rule.disambiguate("gerigi"), None);
+    }
+}
@@ -0,0 +1,47 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 41: kuA -> ku-A
+pub struct Rule41 {
+    regex: Regex,
+}
+
+impl Rule41 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^ku(.+)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule41 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule41 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod rule_41_test {
+    use super::*;
+
+    #[test]
+    fn rule_41_should_disambiguate() {
+        let rule = Rule41::new();
+        assert_eq!(rule.disambiguate("kuambil"), Some(String::from("ambil")));
+        assert_eq!(rule.disambiguate("kubaca"), Some(String::from("baca")));
+    }
+
+    #[test]
+    fn rule_41_should_ignore_other_word() {
+        let rule = Rule41::new();
+        assert_eq!(rule.disambiguate("ku"), None);
+        assert_eq!(rule.disambiguate("aku"), None);
+    }
+}
@@ -0,0 +1,47 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 5: beC1erC2 -> be-C1erC2 where C1 != 'r'
+pub struct Rule5 {
+    regex: Regex,
+}
+
+impl Rule5 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^be([bcdfghjklmnpqstvwxyz])(er[bcdfghjklmnpqrstvwxyz].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule5 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule5 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_5_test {
+    use super::*;
+
+    #[test]
+    fn rule_5_should_disambiguate() {
+        let rule = Rule5::new();
+        assert_eq!(rule.disambiguate("bekerja"), Some(String::from("kerja")));
+        assert_eq!(rule.disambiguate("beternak"), Some(String::from("ternak")));
+    }
+
+    #[test]
+    fn rule_5_should_ignore_other_word() {
+        let rule = Rule5::new();
+        assert_eq!(rule.disambiguate("berernak"), None);
+        assert_eq!(rule.disambiguate("belajar"), None);
+    }
+}
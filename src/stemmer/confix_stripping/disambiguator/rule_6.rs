@@ -0,0 +1,84 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 6a: terV -> ter-V
+pub struct Rule6a {
+    regex: Regex,
+}
+
+impl Rule6a {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^ter([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule6a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule6a {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(captures[1].to_string())
+    }
+}
+
+/// Disambiguator prefix rule 6b: terV -> te-rV
+pub struct Rule6b {
+    regex: Regex,
+}
+
+impl Rule6b {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^ter([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule6b {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule6b {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("r{}", &captures[1]))
+    }
+}
+
+#[cfg(test)]
+mod rule_6_test {
+    use super::*;
+
+    #[test]
+    fn rule_6a_should_disambiguate() {
+        let rule = Rule6a::new();
+        assert_eq!(rule.disambiguate("terancam"), Some(String::from("ancam")));
+    }
+
+    #[test]
+    fn rule_6a_should_ignore_other_word() {
+        let rule = Rule6a::new();
+        assert_eq!(rule.disambiguate("tertangkap"), None);
+    }
+
+    #[test]
+    fn rule_6b_should_disambiguate() {
+        let rule = Rule6b::new();
+        assert_eq!(rule.disambiguate("terancam"), Some(String::from("rancam")));
+        assert_eq!(rule.disambiguate("teraih"), Some(String::from("raih")));
+    }
+
+    #[test]
+    fn rule_6b_should_ignore_other_word() {
+        let rule = Rule6b::new();
+        assert_eq!(rule.disambiguate("tertangkap"), None);
+    }
+}
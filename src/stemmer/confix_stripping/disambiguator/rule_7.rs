@@ -0,0 +1,47 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 7: terCerV -> ter-CerV where C != 'r'
+pub struct Rule7 {
+    regex: Regex,
+}
+
+impl Rule7 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^ter([bcdfghjklmnpqstvwxyz])er([aiueo].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule7 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule7 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}er{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_7_test {
+    use super::*;
+
+    #[test]
+    fn rule_7_should_disambiguate() {
+        let rule = Rule7::new();
+        assert_eq!(rule.disambiguate("terperangkap"), Some(String::from("perangkap")));
+        assert_eq!(rule.disambiguate("tergerak"), Some(String::from("gerak")));
+    }
+
+    #[test]
+    fn rule_7_should_ignore_other_word() {
+        let rule = Rule7::new();
+        assert_eq!(rule.disambiguate("terrerak"), None);
+        assert_eq!(rule.disambiguate("tertangkap"), None);
+    }
+}
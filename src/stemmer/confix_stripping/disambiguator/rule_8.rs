@@ -0,0 +1,50 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 8: terCP -> ter-CP where C != 'r' and P != 'er'
+pub struct Rule8 {
+    regex: Regex,
+}
+
+impl Rule8 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^ter([bcdfghjklmnpqstvwxyz])(.*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule8 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule8 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        if captures[2].starts_with("er") {
+            return None;
+        }
+        Some(format!("{}{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_8_test {
+    use super::*;
+
+    #[test]
+    fn rule_8_should_disambiguate() {
+        let rule = Rule8::new();
+        assert_eq!(rule.disambiguate("tertangkap"), Some(String::from("tangkap")));
+        assert_eq!(rule.disambiguate("terbuka"), Some(String::from("buka")));
+    }
+
+    #[test]
+    fn rule_8_should_ignore_other_word() {
+        let rule = Rule8::new();
+        assert_eq!(rule.disambiguate("terperangkap"), None);
+        assert_eq!(rule.disambiguate("terrusak"), None);
+    }
+}
@@ -0,0 +1,47 @@
+use regex::Regex;
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+
+/// Disambiguator prefix rule 9: teC1erC2 -> te-C1erC2 where C1 != 'r'
+pub struct Rule9 {
+    regex: Regex,
+}
+
+impl Rule9 {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^te([bcdfghjklmnpqstvwxyz])er([bcdfghjklmnpqrstvwxyz].*)$").unwrap(),
+        }
+    }
+}
+
+impl Default for Rule9 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for Rule9 {
+    fn disambiguate(&self, word: &str) -> Option<String> {
+        let captures = self.regex.captures(word)?;
+        Some(format!("{}er{}", &captures[1], &captures[2]))
+    }
+}
+
+#[cfg(test)]
+mod rule_9_test {
+    use super::*;
+
+    #[test]
+    fn rule_9_should_disambiguate() {
+        let rule = Rule9::new();
+        assert_eq!(rule.disambiguate("tepercaya"), Some(String::from("percaya")));
+        assert_eq!(rule.disambiguate("teterbang"), Some(String::from("terbang")));
+    }
+
+    #[test]
+    fn rule_9_should_ignore_other_word() {
+        let rule = Rule9::new();
+        assert_eq!(rule.disambiguate("terercaya"), None);
+        assert_eq!(rule.disambiguate("terancam"), None);
+    }
+}
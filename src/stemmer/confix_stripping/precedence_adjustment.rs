@@ -23,13 +23,26 @@ impl PrecedenceAdjustment {
         }
     }
 
+    /// Initialize with custom rules.
+    ///
+    /// A word matching any of the given regex has its prefix removed before its suffix.
+    pub fn from(regex_list: Vec<Regex>) -> Self {
+        Self { regex_list }
+    }
+
     pub fn is_satisfied_by(&self, word: &str) -> bool {
         for r in &self.regex_list {
             if r.is_match(word) {
                 return true;
             }
         }
-        return false;
+        false
+    }
+}
+
+impl Default for PrecedenceAdjustment {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -37,6 +50,13 @@ impl PrecedenceAdjustment {
 mod precedence_adjustment_test {
     use super::*;
 
+    #[test]
+    fn should_satisfy_custom_rules() {
+        let pa = PrecedenceAdjustment::from(vec![Regex::new(r"^ke(.*)an$").unwrap()]);
+        assert_eq!(pa.is_satisfied_by("kebersihan"), true);
+        assert_eq!(pa.is_satisfied_by("benarkanlah"), false);
+    }
+
     #[test]
     fn should_not_satisfy() {
         let pa = PrecedenceAdjustment::new();
@@ -1,89 +1,475 @@
-use crate::dictionary::Dictionary;
-use crate::stemmer::confix_stripping::precedence_adjustment::PrecedenceAdjustment;
-// use crate::stemmer::context::removal::{Removal};
-use crate::stemmer::context::visitor::{Visitor, VisitorConfiguration, VisitorResult};
-use crate::stemmer::context::visitor::dont_stem_short_word::DontStemShortWord;
+use crate::error::{Error, Result};
+use crate::lexicon::Lexicon;
+use crate::stemmer::context::candidate::Candidate;
+use crate::stemmer::context::derivation::{Derivation, DerivationStep, RulePrecedence};
+use crate::stemmer::context::removal::{AffixType, Removal};
+use crate::stemmer::context::visitor::{Visitor, VisitorResult};
+use crate::stemmer::pipeline::Pipeline;
 
-// pub mod removal;
+pub mod candidate;
+pub mod derivation;
+pub mod removal;
 pub mod visitor;
 
+/// Maximum number of derivational prefixes that can be removed from a word.
+///
+/// Indonesian words can carry up to three stacked prefixes, e.g. "di-per-ke-" in "diperkemukakan".
+const MAX_PREFIX_REMOVAL: usize = 3;
+
+/// Per-word state of the stemming process.
+///
+/// The visitors and rules are borrowed from a shared `Pipeline`,
+/// and a context can be `reset` to stem another word while keeping its allocations.
 pub struct Context<'a> {
-    original_word: &'a str,
+    original_word: String,
     current_word: String,
     result_word: Option<String>,
     is_process_stopped: bool,
-    // removal_list: Vec<Removal>,
-    dictionary: &'a Dictionary,
-    general_visitors: Vec<Box<dyn Visitor>>,
-    prefix_visitors: Vec<Box<dyn Visitor>>,
-    suffix_visitors: Vec<Box<dyn Visitor>>,
+    is_root_found: bool,
+    removal_list: Vec<Removal>,
+    derivation_steps: Option<Vec<DerivationStep>>,
+    candidates: Option<Vec<Candidate>>,
+    dictionary: &'a dyn Lexicon,
+    pipeline: &'a Pipeline,
 }
 
 impl<'a> Context<'a> {
-    pub fn new(original_word: &'a str, dictionary: &'a Dictionary, visitor_configuration: Option<VisitorConfiguration>) -> Self {
-        let mut visitor_configuration = visitor_configuration.unwrap_or(VisitorConfiguration::default());
+    /// Initialize context for the given word.
+    ///
+    /// The default pipeline, shared by the whole process, is used if `pipeline` is None.
+    pub fn new(original_word: &str, dictionary: &'a dyn Lexicon, pipeline: Option<&'a Pipeline>) -> Self {
         Self {
-            original_word,
+            original_word: original_word.to_string(),
             current_word: original_word.to_string(),
             result_word: None,
             is_process_stopped: false,
+            is_root_found: false,
             dictionary,
-            general_visitors: std::mem::replace(&mut visitor_configuration.general_visitors, Vec::new()),
-            prefix_visitors: std::mem::replace(&mut visitor_configuration.prefix_visitors, Vec::new()),
-            suffix_visitors: std::mem::replace(&mut visitor_configuration.suffix_visitors, Vec::new()),
-            // removal_list: vec![],
+            pipeline: pipeline.unwrap_or_else(|| Pipeline::shared_default()),
+            removal_list: vec![],
+            derivation_steps: None,
+            candidates: None,
+        }
+    }
+
+    /// Prepare the context to stem another word.
+    ///
+    /// The previous result, removals and derivation steps are cleared,
+    /// but the allocated buffers are kept. Derivation and candidates stay enabled if they were.
+    pub fn reset(&mut self, original_word: &str) {
+        self.original_word.clear();
+        self.original_word.push_str(original_word);
+        self.current_word.clear();
+        self.current_word.push_str(original_word);
+        self.result_word = None;
+        self.is_process_stopped = false;
+        self.is_root_found = false;
+        self.removal_list.clear();
+        if let Some(steps) = &mut self.derivation_steps {
+            steps.clear();
+        }
+        if let Some(candidates) = &mut self.candidates {
+            candidates.clear();
         }
     }
 
+    /// Keep track of every step taken by the stemming process.
+    /// The steps can then be retrieved with .get_derivation()
+    pub fn enable_derivation(&mut self) {
+        self.derivation_steps = Some(vec![]);
+    }
+
+    /// Keep searching for affixes after a root word is found, and collect every root word found on the way.
+    /// The root words can then be retrieved with .get_candidates()
+    ///
+    /// The resulting word stays the first root word found, as without candidates.
+    pub fn enable_candidates(&mut self) {
+        self.candidates = Some(vec![]);
+    }
+
     /// Execute the stemming process.
-    /// The result can then be retrieved with .get_result()
+    /// The result can then be retrieved with .get_resulting_word()
     pub fn execute(&mut self) {
+        // step 1 - 5
+        self.start_stemming_process();
+
+        let first_candidate = self.candidates.as_ref()
+            .and_then(|candidates| candidates.first())
+            .map(|candidate| candidate.root_word.clone());
 
+        // step 6
+        if let Some(root_word) = first_candidate {
+            self.is_root_found = true;
+            self.result_word = Some(root_word);
+        } else if self.is_root_word() {
+            self.is_root_found = true;
+            self.result_word = Some(self.current_word.clone());
+        } else {
+            self.result_word = Some(self.original_word.clone());
+        }
+    }
+
+    /// Runs the Enhanced Confix Stripping steps until a root word is found in the dictionary.
+    fn start_stemming_process(&mut self) {
         // step 1
-        if self.dictionary.contains(&self.current_word) {
+        if self.is_root_word() {
             return;
         }
 
-        // Iterate each visitor and run its modifier
-        for visitor in &(self.general_visitors) {
-            let visitor_result: VisitorResult = visitor.visit(&self);
-            match visitor_result {
-                VisitorResult::StopProcess => { self.is_process_stopped = true; }
-                VisitorResult::DoNothing => {}
+        let pipeline = self.pipeline;
+        self.accept_visitors(pipeline.get_general_visitors());
+
+        if self.is_process_stopped || self.is_root_word() {
+            return;
+        }
+
+        // Confix Stripping: trying to remove prefix before suffix if the specification is met
+        if pipeline.uses_precedence_adjustment() && pipeline.get_precedence_adjustment().is_satisfied_by(&self.original_word) {
+            self.record(DerivationStep::RulePrecedence(RulePrecedence::PrefixFirst));
+
+            // step 4, 5
+            self.remove_prefixes();
+            if self.is_root_word() {
+                return;
+            }
+
+            // step 2, 3
+            self.remove_suffixes();
+            if self.is_root_word() {
+                return;
             }
-            if !self.is_process_stopped {
+
+            // the trial is failed: restore the original word and continue
+            // with the normal rule precedence (suffix first, prefix afterwards)
+            self.current_word.clone_from(&self.original_word);
+            self.removal_list.clear();
+            self.is_process_stopped = false;
+            self.record(DerivationStep::RestoreOriginalWord);
+        }
+
+        self.record(DerivationStep::RulePrecedence(RulePrecedence::SuffixFirst));
+
+        // step 2, 3
+        self.remove_suffixes();
+        if self.is_root_word() {
+            return;
+        }
+
+        // step 4, 5
+        self.remove_prefixes();
+        if self.is_root_word() {
+            return;
+        }
+
+        // ECS loop pengembalian akhiran
+        if pipeline.uses_suffix_restoration() {
+            self.loop_pengembalian_akhiran();
+        }
+    }
+
+    /// Enhanced Confix Stripping "loop pengembalian akhiran" (suffix restoration loop).
+    ///
+    /// When no root word is found, the suffixes might have been removed too eagerly,
+    /// e.g. "-kan" in "penolakan" actually is "tolak" + "-an".
+    /// Every removed prefix is restored, then each removed suffix is restored from the last one,
+    /// and the prefixes are removed again to look for a root word.
+    fn loop_pengembalian_akhiran(&mut self) {
+        self.record(DerivationStep::RestoreSuffix);
+
+        // restore prefix to form [DP+[DP+[DP]]] + Root word
+        self.restore_prefix();
+
+        let removal_list = self.removal_list.clone();
+        let current_word = self.current_word.clone();
+
+        for (index, removal) in removal_list.iter().enumerate().rev() {
+            if !removal.get_affix_type().is_suffix() {
+                continue;
+            }
+
+            // the restored suffix, and every suffix removed after it, are no longer removed
+            self.removal_list.truncate(index);
+
+            if removal.get_removed_part() == "kan" {
+                // "-kan" might actually be "k" + "-an"
+                let subject = format!("{}kan", removal.get_result());
+                let result = format!("{}k", removal.get_result());
+                self.removal_list.push(Removal::new(removal.get_visitor_name(), &subject, &result, removal.get_affix_type()));
+                self.current_word = result;
+
+                // step 4, 5
+                self.remove_prefixes();
+                if self.is_root_word() {
+                    return;
+                }
+
+                self.removal_list.truncate(index);
+                self.current_word = subject;
+            } else {
+                self.current_word = removal.get_subject().to_string();
+            }
+
+            // step 4, 5
+            self.remove_prefixes();
+            if self.is_root_word() {
+                return;
+            }
+
+            self.removal_list = removal_list.clone();
+            self.current_word = current_word.clone();
+        }
+    }
+
+    /// Restores every removed prefix, along with its removal record
+    fn restore_prefix(&mut self) {
+        let first_prefix_removal = self.removal_list.iter()
+            .find(|removal| removal.get_affix_type() == AffixType::DerivationalPrefix);
+        if let Some(removal) = first_prefix_removal {
+            self.current_word = removal.get_subject().to_string();
+        }
+        self.removal_list.retain(|removal| removal.get_affix_type() != AffixType::DerivationalPrefix);
+    }
+
+    /// Removes inflectional and derivational suffixes (step 2 and 3).
+    fn remove_suffixes(&mut self) {
+        let pipeline = self.pipeline;
+        self.accept_visitors(pipeline.get_suffix_visitors());
+    }
+
+    /// Removes derivational prefixes (step 4 and 5).
+    ///
+    /// Each iteration removes at most one prefix, up to `MAX_PREFIX_REMOVAL` times.
+    fn remove_prefixes(&mut self) {
+        let pipeline = self.pipeline;
+        for _ in 0..MAX_PREFIX_REMOVAL {
+            self.accept_prefix_visitors(pipeline.get_prefix_visitors());
+            if self.is_process_stopped || self.is_root_word() {
                 break;
             }
         }
+    }
+
+    /// Run each visitor in order until the process is stopped or a root word is found.
+    fn accept_visitors(&mut self, visitors: &[Box<dyn Visitor>]) {
+        for visitor in visitors {
+            self.accept(visitor.as_ref());
+            if self.is_process_stopped || self.is_root_word() {
+                return;
+            }
+        }
+    }
+
+    /// Same as `accept_visitors`, but also returns as soon as a single prefix has been removed.
+    fn accept_prefix_visitors(&mut self, visitors: &[Box<dyn Visitor>]) {
+        let removal_count = self.removal_list.len();
+        for visitor in visitors {
+            self.accept(visitor.as_ref());
+            if self.is_process_stopped || self.is_root_word() {
+                return;
+            }
+            if self.removal_list.len() > removal_count {
+                return;
+            }
+        }
+    }
+
+    /// Returns the word given to the stemming process
+    pub fn get_original_word(&self) -> &str {
+        &self.original_word
+    }
+
+    /// Returns the word being stemmed, i.e. the original word minus every affix removed so far
+    pub fn get_current_word(&self) -> &str {
+        &self.current_word
+    }
+
+    /// Replaces the word being stemmed, without recording any removal.
+    ///
+    /// Use `remove_affix` instead when an affix is stripped,
+    /// so the removal can be restored by the suffix restoration loop.
+    pub fn set_current_word(&mut self, word: String) {
+        self.current_word = word;
+    }
+
+    /// Returns the affix removals done so far, in order
+    pub fn get_removals(&self) -> &[Removal] {
+        &self.removal_list
+    }
+
+    /// Returns true if the resulting word is found in the dictionary, or accepted as root word by a visitor.
+    ///
+    /// Returns false if the process is not executed yet.
+    pub fn is_root_found(&self) -> bool {
+        self.is_root_found
+    }
+
+    /// Returns true if a visitor stopped the stemming process
+    pub fn is_process_stopped(&self) -> bool {
+        self.is_process_stopped
+    }
+
+    /// Replaces the current word with the result of an affix removal, and records the removal.
+    ///
+    /// Nothing is recorded if the result is the same as the current word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::stemmer::context::Context;
+    /// use rustrawi::stemmer::context::removal::AffixType;
+    /// use rustrawi::stemmer::context::visitor::{Visitor, VisitorResult, VisitorType};
+    ///
+    /// struct RemoveNgeSlangPrefix;
+    ///
+    /// impl Visitor for RemoveNgeSlangPrefix {
+    ///     fn get_visitor_type(&self) -> VisitorType {
+    ///         VisitorType::PrefixVisitor
+    ///     }
+    ///
+    ///     fn visit(&self, context: &mut Context) -> VisitorResult {
+    ///         if let Some(word) = context.get_current_word().strip_prefix("nge") {
+    ///             let word = word.to_string();
+    ///             context.remove_affix(self, word, AffixType::DerivationalPrefix);
+    ///         }
+    ///         VisitorResult::DoNothing
+    ///     }
+    /// }
+    ///
+    /// let dictionary = Dictionary::new();
+    /// let mut context = Context::new("ngegas", &dictionary, None);
+    /// RemoveNgeSlangPrefix.visit(&mut context);
+    /// assert_eq!(context.get_current_word(), "gas");
+    /// assert_eq!(context.get_removals()[0].get_removed_part(), "nge");
+    /// ```
+    pub fn remove_affix(&mut self, visitor: &dyn Visitor, result: String, affix_type: AffixType) {
+        if result == self.current_word {
+            return;
+        }
+        let removal = Removal::new(visitor.get_visitor_name(), &self.current_word, &result, affix_type);
+        self.record(DerivationStep::Removal(removal.clone()));
+        self.removal_list.push(removal);
+        self.current_word = result;
+    }
 
-        if self.dictionary.contains(&self.current_word) {
+    /// Checks whether the current word is a root word found in the dictionary,
+    /// or accepted as root word by a visitor.
+    ///
+    /// When candidates are enabled, a root word found in the dictionary is collected
+    /// and false is returned, so the search goes on.
+    fn is_root_word(&mut self) -> bool {
+        if self.is_root_found {
+            self.add_candidate();
+            return true;
+        }
+        let found = self.dictionary.contains(&self.current_word);
+        if self.derivation_steps.is_some() {
+            let word = self.current_word.clone();
+            self.record(DerivationStep::DictionaryLookup { word, found });
+        }
+        if found && self.candidates.is_some() {
+            self.add_candidate();
+            return false;
+        }
+        found
+    }
+
+    /// Collects the current word as candidate, unless it has been found before
+    fn add_candidate(&mut self) {
+        let Some(candidates) = &mut self.candidates else { return };
+        if candidates.iter().any(|candidate| candidate.root_word == self.current_word) {
             return;
         }
+        candidates.push(Candidate {
+            root_word: self.current_word.clone(),
+            removals: self.removal_list.clone(),
+            frequency: self.dictionary.frequency(&self.current_word).unwrap_or(0),
+        });
+    }
 
-        // Confix Stripping: trying to remove prefix before suffix if the specification is met
-        let precedence_adjustment = PrecedenceAdjustment::new();
-        if precedence_adjustment.is_satisfied_by(self.original_word) {
-            todo!("step 4, 5");
-            todo!("step 2, 3");
+    /// Checks whether the given word is found in the dictionary.
+    ///
+    /// The lookup is recorded in the derivation, if enabled.
+    pub fn lookup(&mut self, word: &str) -> bool {
+        let found = self.dictionary.contains(word);
+        self.record(DerivationStep::DictionaryLookup { word: word.to_string(), found });
+        found
+    }
+
+    fn record(&mut self, step: DerivationStep) {
+        if let Some(steps) = &mut self.derivation_steps {
+            steps.push(step);
+        }
+    }
+
+    fn accept(&mut self, visitor: &dyn Visitor) {
+        // the visit is recorded first, so the steps taken by the visitor come after it
+        let step_index = self.derivation_steps.as_ref().map(|steps| steps.len());
+        self.record(DerivationStep::Visit {
+            visitor_name: visitor.get_visitor_name(),
+            visitor_type: visitor.get_visitor_type(),
+            word: self.current_word.clone(),
+            result: VisitorResult::DoNothing,
+        });
+
+        let visitor_result = visitor.visit(self);
+        if let (Some(steps), Some(index)) = (&mut self.derivation_steps, step_index) {
+            if let DerivationStep::Visit { result, .. } = &mut steps[index] {
+                *result = visitor_result;
+            }
         }
 
-        // todo: complete the logic here
+        match visitor_result {
+            VisitorResult::StopProcess => { self.is_process_stopped = true; }
+            VisitorResult::FoundRoot => {
+                self.is_process_stopped = true;
+                self.is_root_found = true;
+            }
+            VisitorResult::DoNothing => {}
+        }
     }
 
     /// Returns the resulting word from stemming process
+    ///
+    /// # Panics
+    ///
+    /// Panics if the process is not executed yet, see `try_get_resulting_word` for the fallible version.
     pub fn get_resulting_word(&self) -> String {
-        if self.result_word.is_some() {
-            let cloned_word = self.result_word.clone();
-            cloned_word.unwrap()
-        } else {
-            panic!("Resulting word is being called before available")
+        match self.try_get_resulting_word() {
+            Ok(word) => word,
+            Err(e) => panic!("{}", e),
         }
     }
+
+    /// Returns the resulting word from stemming process, or an error if the process is not executed yet
+    pub fn try_get_resulting_word(&self) -> Result<String> {
+        self.result_word.clone().ok_or(Error::ResultNotAvailable)
+    }
+
+    /// Returns every root word found by the stemming process, in the order they are found.
+    ///
+    /// Returns None if the candidates are not enabled.
+    pub fn get_candidates(&self) -> Option<&[Candidate]> {
+        self.candidates.as_deref()
+    }
+
+    /// Returns the steps taken by the stemming process.
+    ///
+    /// Returns None if the derivation is not enabled, or the process is not executed yet.
+    pub fn get_derivation(&self) -> Option<Derivation> {
+        Some(Derivation {
+            original_word: self.original_word.clone(),
+            resulting_word: self.result_word.clone()?,
+            steps: self.derivation_steps.clone()?,
+            parts: vec![],
+        })
+    }
 }
 
 #[cfg(test)]
 mod context_test {
     use super::*;
+    use crate::dictionary::Dictionary;
 
     #[test]
     fn should_stop_process_on_stop_process_result() {
@@ -102,4 +488,470 @@ mod context_test {
         context.execute();
         assert_eq!(context.is_process_stopped, false);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod context_visitor_api_test {
+    use super::*;
+    use crate::dictionary::Dictionary;
+    use crate::stemmer::algorithm::Algorithm;
+    use crate::stemmer::confix_stripping::precedence_adjustment::PrecedenceAdjustment;
+    use crate::stemmer::context::visitor::{VisitorConfiguration, VisitorType};
+    use crate::stemmer::stemming_mode::StemmingMode;
+
+    /// Accepts any word ending with "wati" as a root word, e.g. a personal name.
+    struct AcceptPersonalName;
+
+    impl Visitor for AcceptPersonalName {
+        fn get_visitor_type(&self) -> VisitorType {
+            VisitorType::GeneralVisitor
+        }
+
+        fn visit(&self, context: &mut Context) -> VisitorResult {
+            if context.get_current_word().ends_with("wati") {
+                return VisitorResult::FoundRoot;
+            }
+            VisitorResult::DoNothing
+        }
+    }
+
+    fn pipeline() -> Pipeline {
+        let mut configuration = VisitorConfiguration::default();
+        configuration.general_visitors.push(Box::new(AcceptPersonalName));
+        Pipeline::try_with_mode(StemmingMode::Full, Algorithm::default(), configuration, PrecedenceAdjustment::new()).unwrap()
+    }
+
+    #[test]
+    fn should_expose_words() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("membaca", &dictionary, None);
+        assert_eq!(context.get_original_word(), "membaca");
+        assert_eq!(context.get_current_word(), "membaca");
+
+        context.set_current_word(String::from("baca"));
+        assert_eq!(context.get_original_word(), "membaca");
+        assert_eq!(context.get_current_word(), "baca");
+        assert_eq!(context.get_removals().len(), 0);
+    }
+
+    #[test]
+    fn should_accept_current_word_on_found_root_result() {
+        let dictionary = Dictionary::new();
+        let pipeline = pipeline();
+        let mut context = Context::new("suwati", &dictionary, Some(&pipeline));
+        context.execute();
+        assert_eq!(context.is_process_stopped(), true);
+        assert_eq!(context.get_resulting_word(), "suwati");
+    }
+
+    #[test]
+    fn should_continue_on_do_nothing_result() {
+        let dictionary = Dictionary::from_list(vec!["baca"]);
+        let pipeline = pipeline();
+        let mut context = Context::new("membaca", &dictionary, Some(&pipeline));
+        context.execute();
+        assert_eq!(context.is_process_stopped(), false);
+        assert_eq!(context.get_resulting_word(), "baca");
+    }
+
+    #[test]
+    fn should_tell_whether_root_is_found() {
+        let dictionary = Dictionary::from_list(vec!["baca"]);
+        let mut context = Context::new("membaca", &dictionary, None);
+        assert_eq!(context.is_root_found(), false);
+        context.execute();
+        assert_eq!(context.is_root_found(), true);
+
+        context.reset("menulis");
+        context.execute();
+        assert_eq!(context.is_root_found(), false);
+    }
+
+    #[test]
+    fn should_record_lookup() {
+        let dictionary = Dictionary::from_list(vec!["baca"]);
+        let mut context = Context::new("membaca", &dictionary, None);
+        context.enable_derivation();
+        assert_eq!(context.lookup("baca"), true);
+        assert_eq!(context.derivation_steps.unwrap()[0], DerivationStep::DictionaryLookup { word: String::from("baca"), found: true });
+    }
+}
+
+#[cfg(test)]
+mod context_phase_test {
+    use super::*;
+    use crate::dictionary::Dictionary;
+    use crate::stemmer::algorithm::Algorithm;
+    use crate::stemmer::confix_stripping::precedence_adjustment::PrecedenceAdjustment;
+    use crate::stemmer::context::visitor::{VisitorConfiguration, VisitorType};
+    use crate::stemmer::stemming_mode::StemmingMode;
+    use crate::stemmer::context::visitor::dont_stem_short_word::DontStemShortWord;
+
+    /// Returns a fixed result, and appends its name to the current word to observe the visit order.
+    struct Mark(&'static str, VisitorType, VisitorResult);
+
+    impl Visitor for Mark {
+        fn get_visitor_type(&self) -> VisitorType {
+            self.1
+        }
+
+        fn visit(&self, context: &mut Context) -> VisitorResult {
+            context.current_word.push_str(self.0);
+            self.2
+        }
+    }
+
+    fn execute(visitors: Vec<Mark>) -> String {
+        let dictionary = Dictionary::new();
+        let mut configuration = VisitorConfiguration::empty();
+        configuration.add_visitor(Box::new(DontStemShortWord::new()));
+        for visitor in visitors {
+            configuration.add_visitor(Box::new(visitor));
+        }
+        let pipeline = Pipeline::try_with_mode(StemmingMode::Full, Algorithm::default(), configuration, PrecedenceAdjustment::new()).unwrap();
+        let mut context = Context::new("kata", &dictionary, Some(&pipeline));
+        context.execute();
+        context.current_word
+    }
+
+    #[test]
+    fn should_run_every_general_visitor() {
+        let visitors = vec![
+            Mark("1", VisitorType::GeneralVisitor, VisitorResult::DoNothing),
+            Mark("2", VisitorType::GeneralVisitor, VisitorResult::DoNothing),
+        ];
+        assert_eq!(execute(visitors), "kata12");
+    }
+
+    #[test]
+    fn should_skip_other_phases_when_general_visitor_stops_process() {
+        let visitors = vec![
+            Mark("1", VisitorType::GeneralVisitor, VisitorResult::StopProcess),
+            Mark("2", VisitorType::GeneralVisitor, VisitorResult::DoNothing),
+            Mark("s", VisitorType::SuffixVisitor, VisitorResult::DoNothing),
+            Mark("p", VisitorType::PrefixVisitor, VisitorResult::DoNothing),
+        ];
+        assert_eq!(execute(visitors), "kata1");
+    }
+
+    #[test]
+    fn should_run_suffix_phase_before_prefix_phase() {
+        let visitors = vec![
+            Mark("p", VisitorType::PrefixVisitor, VisitorResult::DoNothing),
+            Mark("s", VisitorType::SuffixVisitor, VisitorResult::DoNothing),
+        ];
+        // prefix visitors run up to three times, as none of them removes a prefix
+        assert_eq!(execute(visitors), "katasppp");
+    }
+
+    #[test]
+    fn should_stop_suffix_phase_on_found_root() {
+        let visitors = vec![
+            Mark("1", VisitorType::SuffixVisitor, VisitorResult::FoundRoot),
+            Mark("2", VisitorType::SuffixVisitor, VisitorResult::DoNothing),
+            Mark("p", VisitorType::PrefixVisitor, VisitorResult::DoNothing),
+        ];
+        assert_eq!(execute(visitors), "kata1");
+    }
+}
+
+#[cfg(test)]
+mod context_reset_test {
+    use super::*;
+    use crate::dictionary::Dictionary;
+
+    #[test]
+    fn should_stem_another_word_after_reset() {
+        let dictionary = Dictionary::from_list(vec!["baca", "tulis"]);
+        let mut context = Context::new("membaca", &dictionary, None);
+        context.enable_derivation();
+        context.execute();
+        assert_eq!(context.get_resulting_word(), "baca");
+
+        context.reset("ditulis");
+        assert_eq!(matches!(context.try_get_resulting_word(), Err(Error::ResultNotAvailable)), true);
+        assert_eq!(context.removal_list.len(), 0);
+
+        context.execute();
+        assert_eq!(context.get_resulting_word(), "tulis");
+        let derivation = context.get_derivation().unwrap();
+        assert_eq!(derivation.original_word, "ditulis");
+        assert_eq!(derivation.get_removals().len(), 1);
+    }
+
+    #[test]
+    fn should_resume_process_after_reset() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("iya", &dictionary, None);
+        context.execute();
+        assert_eq!(context.is_process_stopped, true);
+
+        context.reset("ayam");
+        assert_eq!(context.is_process_stopped, false);
+    }
+}
+
+#[cfg(test)]
+mod context_resulting_word_test {
+    use super::*;
+    use crate::dictionary::Dictionary;
+
+    #[test]
+    #[should_panic(expected = "Resulting word is being called before available")]
+    fn should_panic_before_execute() {
+        let dictionary = Dictionary::new();
+        let context = Context::new("ayam", &dictionary, None);
+        context.get_resulting_word();
+    }
+
+    #[test]
+    fn should_return_error_before_execute() {
+        let dictionary = Dictionary::new();
+        let context = Context::new("ayam", &dictionary, None);
+        assert_eq!(matches!(context.try_get_resulting_word(), Err(Error::ResultNotAvailable)), true);
+    }
+
+    #[test]
+    fn should_return_resulting_word_after_execute() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("ayam", &dictionary, None);
+        context.execute();
+        assert_eq!(context.try_get_resulting_word().unwrap(), "ayam");
+    }
+}
+
+#[cfg(test)]
+mod context_execute_test {
+    use super::*;
+    use crate::dictionary::Dictionary;
+    use crate::stemmer::algorithm::Algorithm;
+    use crate::stemmer::confix_stripping::precedence_adjustment::PrecedenceAdjustment;
+    use crate::stemmer::context::visitor::{VisitorConfiguration, VisitorType};
+    use crate::stemmer::stemming_mode::StemmingMode;
+    use crate::stemmer::context::visitor::dont_stem_short_word::DontStemShortWord;
+
+    /// Removes a fixed prefix, used to observe the order of the stemming steps.
+    struct RemoveFixedPrefix(&'static str);
+
+    impl Visitor for RemoveFixedPrefix {
+        fn get_visitor_type(&self) -> VisitorType {
+            VisitorType::PrefixVisitor
+        }
+
+        fn visit(&self, context: &mut Context) -> VisitorResult {
+            if let Some(word) = context.current_word.strip_prefix(self.0) {
+                context.remove_affix(self, word.to_string(), AffixType::DerivationalPrefix);
+            }
+            VisitorResult::DoNothing
+        }
+    }
+
+    /// Removes a fixed suffix, used to observe the order of the stemming steps.
+    struct RemoveFixedSuffix(&'static str);
+
+    impl Visitor for RemoveFixedSuffix {
+        fn get_visitor_type(&self) -> VisitorType {
+            VisitorType::SuffixVisitor
+        }
+
+        fn visit(&self, context: &mut Context) -> VisitorResult {
+            if let Some(word) = context.current_word.strip_suffix(self.0) {
+                context.remove_affix(self, word.to_string(), AffixType::DerivationalSuffix);
+            }
+            VisitorResult::DoNothing
+        }
+    }
+
+    fn configuration(prefixes: Vec<&'static str>, suffixes: Vec<&'static str>) -> Pipeline {
+        let mut configuration = VisitorConfiguration {
+            general_visitors: vec![Box::new(DontStemShortWord::new())],
+            prefix_visitors: vec![],
+            suffix_visitors: vec![],
+        };
+        for prefix in prefixes {
+            configuration.prefix_visitors.push(Box::new(RemoveFixedPrefix(prefix)));
+        }
+        for suffix in suffixes {
+            configuration.suffix_visitors.push(Box::new(RemoveFixedSuffix(suffix)));
+        }
+        Pipeline::try_with_mode(StemmingMode::Full, Algorithm::default(), configuration, PrecedenceAdjustment::new()).unwrap()
+    }
+
+    fn stem(word: &str, dictionary: &Dictionary, pipeline: Pipeline) -> String {
+        let mut context = Context::new(word, dictionary, Some(&pipeline));
+        context.execute();
+        context.get_resulting_word()
+    }
+
+    #[test]
+    fn should_return_word_found_in_dictionary() {
+        let dictionary = Dictionary::from_list(vec!["bahagia"]);
+        assert_eq!(stem("bahagia", &dictionary, configuration(vec!["ba"], vec!["gia"])), "bahagia");
+    }
+
+    #[test]
+    fn should_return_original_word_if_root_is_not_found() {
+        let dictionary = Dictionary::new();
+        assert_eq!(stem("membahagiakan", &dictionary, configuration(vec!["mem"], vec!["kan"])), "membahagiakan");
+    }
+
+    #[test]
+    fn should_return_original_word_on_short_word() {
+        let dictionary = Dictionary::from_list(vec!["a"]);
+        assert_eq!(stem("aku", &dictionary, configuration(vec![], vec!["ku"])), "aku");
+    }
+
+    #[test]
+    fn should_remove_suffix() {
+        let dictionary = Dictionary::from_list(vec!["bahagia"]);
+        assert_eq!(stem("bahagiakan", &dictionary, configuration(vec![], vec!["kan"])), "bahagia");
+    }
+
+    #[test]
+    fn should_remove_suffix_then_prefix() {
+        let dictionary = Dictionary::from_list(vec!["bahagia"]);
+        assert_eq!(stem("membahagiakan", &dictionary, configuration(vec!["mem"], vec!["kan"])), "bahagia");
+    }
+
+    #[test]
+    fn should_remove_up_to_three_prefixes() {
+        let dictionary = Dictionary::from_list(vec!["kemuka"]);
+        let configuration = configuration(vec!["di", "per", "ke"], vec!["kan"]);
+        assert_eq!(stem("diperkemukakan", &dictionary, configuration), "kemuka");
+
+        let dictionary = Dictionary::from_list(vec!["muka"]);
+        let configuration = self::configuration(vec!["di", "per", "ke"], vec!["kan"]);
+        assert_eq!(stem("diperkemukakan", &dictionary, configuration), "muka");
+    }
+
+    #[test]
+    fn should_remove_prefix_first_on_precedence_adjustment() {
+        // "bertemulah" satisfies the "be-lah" precedence adjustment,
+        // so the prefix is removed before the suffix is.
+        let dictionary = Dictionary::from_list(vec!["bertemu", "temulah"]);
+        assert_eq!(stem("bertemulah", &dictionary, configuration(vec!["ber"], vec!["lah"])), "temulah");
+
+        // without precedence adjustment, the suffix is removed first
+        let dictionary = Dictionary::from_list(vec!["menemu", "temulah"]);
+        assert_eq!(stem("menemulah", &dictionary, configuration(vec!["men"], vec!["lah"])), "menemu");
+    }
+
+    #[test]
+    fn should_fall_back_to_suffix_first_on_failed_precedence_adjustment() {
+        // "mengeri" satisfies the "me-i" precedence adjustment, but both affixes overlap:
+        // removing the prefix first leaves "ri", which is never a root word.
+        let dictionary = Dictionary::from_list(vec!["meng"]);
+        assert_eq!(stem("mengeri", &dictionary, configuration(vec!["menge"], vec!["eri"])), "meng");
+    }
+
+    #[test]
+    fn should_record_removals() {
+        let dictionary = Dictionary::from_list(vec!["bahagia"]);
+        let pipeline = configuration(vec!["mem"], vec!["kan"]);
+        let mut context = Context::new("membahagiakan", &dictionary, Some(&pipeline));
+        context.execute();
+
+        assert_eq!(context.removal_list.len(), 2);
+        assert_eq!(context.removal_list[0].get_subject(), "membahagiakan");
+        assert_eq!(context.removal_list[0].get_removed_part(), "kan");
+        assert_eq!(context.removal_list[0].get_affix_type(), AffixType::DerivationalSuffix);
+        assert_eq!(context.removal_list[0].get_visitor_name(), "RemoveFixedSuffix");
+        assert_eq!(context.removal_list[1].get_subject(), "membahagia");
+        assert_eq!(context.removal_list[1].get_removed_part(), "mem");
+        assert_eq!(context.removal_list[1].get_affix_type(), AffixType::DerivationalPrefix);
+    }
+
+    #[test]
+    fn should_restore_suffix_when_root_word_is_not_found() {
+        // "nya" is wrongly removed from "bertanya", the root word is "tanya"
+        let dictionary = Dictionary::from_list(vec!["tanya"]);
+        let pipeline = configuration(vec!["ber"], vec!["nya"]);
+        let mut context = Context::new("bertanya", &dictionary, Some(&pipeline));
+        context.execute();
+
+        assert_eq!(context.get_resulting_word(), "tanya");
+        assert_eq!(context.removal_list.len(), 1);
+        assert_eq!(context.removal_list[0].get_subject(), "bertanya");
+        assert_eq!(context.removal_list[0].get_removed_part(), "ber");
+    }
+
+    #[test]
+    fn should_restore_kan_suffix_as_k_an() {
+        // "kan" is wrongly removed from "kedudukan", it actually is "duduk" + "an"
+        let dictionary = Dictionary::from_list(vec!["duduk"]);
+        let pipeline = configuration(vec!["ke"], vec!["kan"]);
+        let mut context = Context::new("kedudukan", &dictionary, Some(&pipeline));
+        context.execute();
+
+        assert_eq!(context.get_resulting_word(), "duduk");
+        assert_eq!(context.removal_list.len(), 2);
+        assert_eq!(context.removal_list[0].get_removed_part(), "an");
+        assert_eq!(context.removal_list[1].get_removed_part(), "ke");
+    }
+
+    #[test]
+    fn should_clear_removals_on_failed_precedence_adjustment() {
+        let dictionary = Dictionary::from_list(vec!["meng"]);
+        let pipeline = configuration(vec!["menge"], vec!["eri"]);
+        let mut context = Context::new("mengeri", &dictionary, Some(&pipeline));
+        context.execute();
+        assert_eq!(context.removal_list.len(), 1);
+        assert_eq!(context.removal_list[0].get_removed_part(), "eri");
+    }
+}
+
+#[cfg(test)]
+mod context_candidates_test {
+    use super::*;
+    use crate::dictionary::Dictionary;
+
+    #[test]
+    fn should_not_collect_candidates_by_default() {
+        let dictionary = Dictionary::from_list(vec!["baca"]);
+        let mut context = Context::new("membaca", &dictionary, None);
+        context.execute();
+        assert_eq!(context.get_candidates(), None);
+    }
+
+    #[test]
+    fn should_collect_every_root_word_found() {
+        let dictionary = Dictionary::from_frequency_list(vec![("beruang", 3), ("uang", 120)]);
+        let mut context = Context::new("beruang", &dictionary, None);
+        context.enable_candidates();
+        context.execute();
+
+        let candidates = context.get_candidates().unwrap();
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].root_word, "beruang");
+        assert_eq!(candidates[0].removals.len(), 0);
+        assert_eq!(candidates[0].frequency, 3);
+        assert_eq!(candidates[1].root_word, "uang");
+        assert_eq!(candidates[1].removals[0].get_removed_part(), "ber");
+        assert_eq!(candidates[1].frequency, 120);
+    }
+
+    #[test]
+    fn should_keep_first_root_word_as_result() {
+        let dictionary = Dictionary::from_list(vec!["beruang", "uang"]);
+        let mut context = Context::new("beruang", &dictionary, None);
+        context.enable_candidates();
+        context.execute();
+        assert_eq!(context.get_resulting_word(), "beruang");
+        assert_eq!(context.is_root_found(), true);
+    }
+
+    #[test]
+    fn should_clear_candidates_on_reset() {
+        let dictionary = Dictionary::from_list(vec!["baca"]);
+        let mut context = Context::new("membaca", &dictionary, None);
+        context.enable_candidates();
+        context.execute();
+        assert_eq!(context.get_candidates().unwrap().len(), 1);
+
+        context.reset("ditulis");
+        assert_eq!(context.get_candidates().unwrap().len(), 0);
+        context.execute();
+        assert_eq!(context.get_candidates().unwrap().len(), 0);
+        assert_eq!(context.get_resulting_word(), "ditulis");
+    }
+}
@@ -0,0 +1,12 @@
+use crate::stemmer::context::removal::Removal;
+
+/// A root word found while searching for affixes, along with the affixes removed to reach it
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Candidate {
+    pub root_word: String,
+    /// Every affix removed from the original word, in order
+    pub removals: Vec<Removal>,
+    /// Frequency of the root word in the dictionary, 0 if it is accepted by a visitor but missing from the dictionary
+    pub frequency: usize,
+}
@@ -0,0 +1,66 @@
+use crate::stemmer::context::removal::Removal;
+use crate::stemmer::context::visitor::{VisitorResult, VisitorType};
+
+/// Order in which the affixes are removed, as decided by the Confix Stripping precedence adjustment
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RulePrecedence {
+    /// Prefixes are removed before suffixes, the word satisfies the precedence adjustment
+    PrefixFirst,
+    /// Suffixes are removed before prefixes, the default rule precedence
+    SuffixFirst,
+}
+
+/// A single step taken while stemming a word
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum DerivationStep {
+    /// A visitor has been run against `word`
+    Visit {
+        visitor_name: &'static str,
+        visitor_type: VisitorType,
+        word: String,
+        result: VisitorResult,
+    },
+    /// An affix has been removed
+    Removal(Removal),
+    /// A word has been looked up in the dictionary
+    DictionaryLookup {
+        word: String,
+        found: bool,
+    },
+    /// The affixes are going to be removed with the given precedence
+    RulePrecedence(RulePrecedence),
+    /// The current word is restored to the original word, after the prefix-first trial has failed
+    RestoreOriginalWord,
+    /// The removed suffixes are going to be restored one by one ("loop pengembalian akhiran")
+    RestoreSuffix,
+    /// No root word is found in the dictionary, so the word is stemmed by the rule-based fallback stemmer
+    RuleBasedFallback {
+        result: String,
+    },
+}
+
+/// Explains how a word is stemmed into its resulting word
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Derivation {
+    pub original_word: String,
+    pub resulting_word: String,
+    /// Every step taken, in order
+    pub steps: Vec<DerivationStep>,
+    /// Derivation of each part of a plural word, e.g. "bertemu" and "temu" in "bertemu-temu".
+    ///
+    /// Empty for singular word.
+    pub parts: Vec<Derivation>,
+}
+
+impl Derivation {
+    /// Returns every affix removal, in order
+    pub fn get_removals(&self) -> Vec<&Removal> {
+        self.steps.iter().filter_map(|step| match step {
+            DerivationStep::Removal(removal) => Some(removal),
+            _ => None,
+        }).collect()
+    }
+}
@@ -0,0 +1,148 @@
+/// Type of an affix removed from a word
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum AffixType {
+    /// Derivational prefix, e.g. "me-", "ber-" or "di-"
+    DerivationalPrefix,
+    /// Derivational suffix "-i", "-kan" or "-an"
+    DerivationalSuffix,
+    /// Inflectional possessive pronoun "-ku", "-mu" or "-nya"
+    PossessivePronoun,
+    /// Inflectional particle "-lah", "-kah", "-tah" or "-pun"
+    Particle,
+}
+
+impl AffixType {
+    /// Returns true if the affix is located at the end of the word
+    pub fn is_suffix(&self) -> bool {
+        !matches!(self, AffixType::DerivationalPrefix)
+    }
+}
+
+/// Record of an affix removed by a visitor
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Removal {
+    visitor_name: &'static str,
+    subject: String,
+    result: String,
+    removed_part: String,
+    affix_type: AffixType,
+}
+
+impl Removal {
+    /// Initialize Removal from the word before and after the affix is removed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::stemmer::context::removal::{AffixType, Removal};
+    /// let removal = Removal::new("PrefixDisambiguator", "menulis", "tulis", AffixType::DerivationalPrefix);
+    /// assert_eq!(removal.get_removed_part(), "men");
+    /// ```
+    pub fn new(visitor_name: &'static str, subject: &str, result: &str, affix_type: AffixType) -> Self {
+        let removed_part = if affix_type.is_suffix() {
+            let common_length = common_prefix_length(subject, result);
+            subject[common_length..].to_string()
+        } else {
+            let common_length = common_suffix_length(subject, result);
+            subject[..subject.len() - common_length].to_string()
+        };
+        Self {
+            visitor_name,
+            subject: subject.to_string(),
+            result: result.to_string(),
+            removed_part,
+            affix_type,
+        }
+    }
+
+    /// Returns the name of the visitor that removed the affix
+    pub fn get_visitor_name(&self) -> &'static str {
+        self.visitor_name
+    }
+
+    /// Returns the word before the affix is removed
+    pub fn get_subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Returns the word after the affix is removed
+    pub fn get_result(&self) -> &str {
+        &self.result
+    }
+
+    /// Returns the removed affix, as it is written in the subject
+    pub fn get_removed_part(&self) -> &str {
+        &self.removed_part
+    }
+
+    /// Returns the type of the removed affix
+    pub fn get_affix_type(&self) -> AffixType {
+        self.affix_type
+    }
+}
+
+/// Returns the byte length of the longest common prefix of both words
+fn common_prefix_length(first: &str, second: &str) -> usize {
+    first.char_indices()
+        .zip(second.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(first.len().min(second.len()), |((index, _), _)| index)
+}
+
+/// Returns the byte length of the longest common suffix of both words
+fn common_suffix_length(first: &str, second: &str) -> usize {
+    first.chars().rev()
+        .zip(second.chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum()
+}
+
+#[cfg(test)]
+mod removal_test {
+    use super::*;
+
+    #[test]
+    fn should_keep_removal_detail() {
+        let removal = Removal::new("RemoveInflectionalParticle", "bacalah", "baca", AffixType::Particle);
+        assert_eq!(removal.get_visitor_name(), "RemoveInflectionalParticle");
+        assert_eq!(removal.get_subject(), "bacalah");
+        assert_eq!(removal.get_result(), "baca");
+        assert_eq!(removal.get_removed_part(), "lah");
+        assert_eq!(removal.get_affix_type(), AffixType::Particle);
+    }
+
+    #[test]
+    fn should_find_removed_suffix() {
+        let removal = Removal::new("RemoveDerivationalSuffix", "mainkan", "main", AffixType::DerivationalSuffix);
+        assert_eq!(removal.get_removed_part(), "kan");
+
+        let removal = Removal::new("RemoveInflectionalPossessivePronoun", "kucing-ku", "kucing", AffixType::PossessivePronoun);
+        assert_eq!(removal.get_removed_part(), "-ku");
+    }
+
+    #[test]
+    fn should_find_removed_prefix() {
+        let removal = Removal::new("RemovePlainPrefix", "dibaca", "baca", AffixType::DerivationalPrefix);
+        assert_eq!(removal.get_removed_part(), "di");
+    }
+
+    #[test]
+    fn should_find_removed_prefix_with_changed_first_letter() {
+        let removal = Removal::new("PrefixDisambiguator", "menyapu", "sapu", AffixType::DerivationalPrefix);
+        assert_eq!(removal.get_removed_part(), "meny");
+
+        let removal = Removal::new("PrefixDisambiguator", "memukul", "pukul", AffixType::DerivationalPrefix);
+        assert_eq!(removal.get_removed_part(), "mem");
+    }
+
+    #[test]
+    fn should_aware_suffix_type() {
+        assert_eq!(AffixType::DerivationalPrefix.is_suffix(), false);
+        assert_eq!(AffixType::DerivationalSuffix.is_suffix(), true);
+        assert_eq!(AffixType::PossessivePronoun.is_suffix(), true);
+        assert_eq!(AffixType::Particle.is_suffix(), true);
+    }
+}
@@ -1,26 +1,63 @@
+pub mod dont_remove_disallowed_confix;
 pub mod dont_stem_short_word;
+pub mod prefix_disambiguator;
+pub mod remove_derivational_suffix;
+pub mod remove_inflectional_particle;
+pub mod remove_inflectional_possessive_pronoun;
+pub mod remove_plain_prefix;
 
+use crate::error::{Error, Result};
+use crate::stemmer::algorithm::Algorithm;
+use crate::stemmer::confix_stripping::disambiguator::*;
 use crate::stemmer::context::Context;
+use crate::stemmer::context::visitor::dont_remove_disallowed_confix::DontRemoveDisallowedConfix;
 use crate::stemmer::context::visitor::dont_stem_short_word::DontStemShortWord;
+use crate::stemmer::context::visitor::prefix_disambiguator::PrefixDisambiguator;
+use crate::stemmer::context::visitor::remove_derivational_suffix::RemoveDerivationalSuffix;
+use crate::stemmer::context::visitor::remove_inflectional_particle::RemoveInflectionalParticle;
+use crate::stemmer::context::visitor::remove_inflectional_possessive_pronoun::RemoveInflectionalPossessivePronoun;
+use crate::stemmer::context::visitor::remove_plain_prefix::RemovePlainPrefix;
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum VisitorType {
     PrefixVisitor,
     GeneralVisitor,
     SuffixVisitor,
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum VisitorResult {
+    /// Stops the stemming process. The current word is the result only if it is found in the dictionary.
     StopProcess,
+    /// Continues the stemming process with the next visitor.
     DoNothing,
+    /// Stops the stemming process, and accepts the current word as the root word
+    /// even if it is not found in the dictionary.
+    FoundRoot,
 }
 
-pub trait Visitor {
+/// A single step of the stemming process.
+///
+/// Visitors inspect the context with `get_current_word`,
+/// strip affixes with `remove_affix`, and tell the process how to go on with a `VisitorResult`.
+/// They can be implemented outside of this crate and run through a custom `Pipeline`.
+pub trait Visitor: Send + Sync {
     fn get_visitor_type(&self) -> VisitorType;
-    fn visit(&self, context: &Context) -> VisitorResult;
+    fn visit(&self, context: &mut Context) -> VisitorResult;
+
+    /// Returns the name of the visitor, used to keep track of which visitor removed an affix
+    fn get_visitor_name(&self) -> &'static str {
+        let type_name = std::any::type_name::<Self>();
+        type_name.rsplit("::").next().unwrap_or(type_name)
+    }
 }
 
+/// Visitors of each phase of the stemming process.
+///
+/// Each visitor must be registered in the phase matching its declared `VisitorType`,
+/// which is checked by `validate` when a `Pipeline` is built.
 pub struct VisitorConfiguration {
     pub general_visitors: Vec<Box<dyn Visitor>>,
     pub prefix_visitors: Vec<Box<dyn Visitor>>,
@@ -28,17 +65,194 @@ pub struct VisitorConfiguration {
 }
 
 impl VisitorConfiguration {
-    pub fn default() -> Self {
+    /// Initialize configuration without any visitor
+    pub fn empty() -> Self {
+        Self {
+            general_visitors: vec![],
+            prefix_visitors: vec![],
+            suffix_visitors: vec![],
+        }
+    }
+
+    /// Appends a visitor to the phase matching its declared `VisitorType`
+    pub fn add_visitor(&mut self, visitor: Box<dyn Visitor>) {
+        let phase = visitor.get_visitor_type();
+        self.get_visitors_mut(phase).push(visitor);
+    }
+
+    /// Returns the visitors of the given phase
+    pub fn get_visitors(&self, phase: VisitorType) -> &[Box<dyn Visitor>] {
+        match phase {
+            VisitorType::GeneralVisitor => &self.general_visitors,
+            VisitorType::PrefixVisitor => &self.prefix_visitors,
+            VisitorType::SuffixVisitor => &self.suffix_visitors,
+        }
+    }
+
+    /// Returns the visitors of the given phase, to be added, removed or reordered
+    pub fn get_visitors_mut(&mut self, phase: VisitorType) -> &mut Vec<Box<dyn Visitor>> {
+        match phase {
+            VisitorType::GeneralVisitor => &mut self.general_visitors,
+            VisitorType::PrefixVisitor => &mut self.prefix_visitors,
+            VisitorType::SuffixVisitor => &mut self.suffix_visitors,
+        }
+    }
+
+    /// Returns an error on the first visitor registered in a phase other than its declared `VisitorType`
+    pub fn validate(&self) -> Result<()> {
+        for phase in [VisitorType::GeneralVisitor, VisitorType::PrefixVisitor, VisitorType::SuffixVisitor] {
+            for visitor in self.get_visitors(phase) {
+                let visitor_type = visitor.get_visitor_type();
+                if visitor_type != phase {
+                    return Err(Error::MisplacedVisitor { visitor_name: visitor.get_visitor_name(), visitor_type, phase });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Initialize configuration with the visitors used by the given algorithm
+    pub fn for_algorithm(algorithm: Algorithm) -> Self {
+        let mut prefix_visitors: Vec<Box<dyn Visitor>> = vec![];
+        if algorithm.checks_disallowed_confixes() {
+            prefix_visitors.push(Box::new(DontRemoveDisallowedConfix));
+        }
+        prefix_visitors.push(Box::new(RemovePlainPrefix::new()));
+        for (rule_number, prefix_disambiguator) in Self::prefix_disambiguators() {
+            if rule_number <= algorithm.last_prefix_rule() {
+                prefix_visitors.push(Box::new(prefix_disambiguator));
+            }
+        }
+
         Self {
             general_visitors: vec![
-                Box::new(DontStemShortWord {}),
+                Box::new(DontStemShortWord::new()),
             ],
-            prefix_visitors: vec![
-                // todo: implement and add other visitor here
+            prefix_visitors,
+            suffix_visitors: vec![
+                Box::new(RemoveInflectionalParticle::new()),
+                Box::new(RemoveInflectionalPossessivePronoun::new()),
+                Box::new(RemoveDerivationalSuffix),
             ],
+        }
+    }
+
+    /// Initialize configuration with the visitors used by `StemmingMode::Light`,
+    /// which only removes particles and possessive pronouns
+    pub fn light() -> Self {
+        Self {
+            general_visitors: vec![
+                Box::new(DontStemShortWord::new()),
+            ],
+            prefix_visitors: vec![],
             suffix_visitors: vec![
-                // todo: implement and add other visitor here
+                Box::new(RemoveInflectionalParticle::new()),
+                Box::new(RemoveInflectionalPossessivePronoun::new()),
             ],
         }
     }
-}
\ No newline at end of file
+
+    /// Returns every prefix disambiguator, along with the number of its rule
+    fn prefix_disambiguators() -> Vec<(usize, PrefixDisambiguator)> {
+        vec![
+            (1, PrefixDisambiguator::new(vec![Box::new(rule_1::Rule1a::new()), Box::new(rule_1::Rule1b::new())])),
+            (2, PrefixDisambiguator::new(vec![Box::new(rule_2::Rule2::new())])),
+            (3, PrefixDisambiguator::new(vec![Box::new(rule_3::Rule3::new())])),
+            (4, PrefixDisambiguator::new(vec![Box::new(rule_4::Rule4::new())])),
+            (5, PrefixDisambiguator::new(vec![Box::new(rule_5::Rule5::new())])),
+            (6, PrefixDisambiguator::new(vec![Box::new(rule_6::Rule6a::new()), Box::new(rule_6::Rule6b::new())])),
+            (7, PrefixDisambiguator::new(vec![Box::new(rule_7::Rule7::new())])),
+            (8, PrefixDisambiguator::new(vec![Box::new(rule_8::Rule8::new())])),
+            (9, PrefixDisambiguator::new(vec![Box::new(rule_9::Rule9::new())])),
+            (10, PrefixDisambiguator::new(vec![Box::new(rule_10::Rule10::new())])),
+            (11, PrefixDisambiguator::new(vec![Box::new(rule_11::Rule11::new())])),
+            (12, PrefixDisambiguator::new(vec![Box::new(rule_12::Rule12::new())])),
+            (13, PrefixDisambiguator::new(vec![Box::new(rule_13::Rule13a::new()), Box::new(rule_13::Rule13b::new())])),
+            (14, PrefixDisambiguator::new(vec![Box::new(rule_14::Rule14::new())])),
+            (15, PrefixDisambiguator::new(vec![Box::new(rule_15::Rule15a::new()), Box::new(rule_15::Rule15b::new())])),
+            (16, PrefixDisambiguator::new(vec![Box::new(rule_16::Rule16::new())])),
+            (17, PrefixDisambiguator::new(vec![Box::new(rule_17::Rule17a::new()), Box::new(rule_17::Rule17b::new()), Box::new(rule_17::Rule17c::new()), Box::new(rule_17::Rule17d::new())])),
+            (18, PrefixDisambiguator::new(vec![Box::new(rule_18::Rule18a::new()), Box::new(rule_18::Rule18b::new())])),
+            (19, PrefixDisambiguator::new(vec![Box::new(rule_19::Rule19::new())])),
+            (20, PrefixDisambiguator::new(vec![Box::new(rule_20::Rule20::new())])),
+            (21, PrefixDisambiguator::new(vec![Box::new(rule_21::Rule21a::new()), Box::new(rule_21::Rule21b::new())])),
+            (23, PrefixDisambiguator::new(vec![Box::new(rule_23::Rule23::new())])),
+            (24, PrefixDisambiguator::new(vec![Box::new(rule_24::Rule24::new())])),
+            (25, PrefixDisambiguator::new(vec![Box::new(rule_25::Rule25::new())])),
+            (26, PrefixDisambiguator::new(vec![Box::new(rule_26::Rule26a::new()), Box::new(rule_26::Rule26b::new())])),
+            (27, PrefixDisambiguator::new(vec![Box::new(rule_27::Rule27::new())])),
+            (28, PrefixDisambiguator::new(vec![Box::new(rule_28::Rule28a::new()), Box::new(rule_28::Rule28b::new())])),
+            (29, PrefixDisambiguator::new(vec![Box::new(rule_29::Rule29::new())])),
+            (30, PrefixDisambiguator::new(vec![Box::new(rule_30::Rule30a::new()), Box::new(rule_30::Rule30b::new()), Box::new(rule_30::Rule30c::new())])),
+            (31, PrefixDisambiguator::new(vec![Box::new(rule_31::Rule31a::new()), Box::new(rule_31::Rule31b::new())])),
+            (32, PrefixDisambiguator::new(vec![Box::new(rule_32::Rule32::new())])),
+            (33, PrefixDisambiguator::new(vec![Box::new(rule_33::Rule33::new())])),
+            (34, PrefixDisambiguator::new(vec![Box::new(rule_34::Rule34::new())])),
+            (35, PrefixDisambiguator::new(vec![Box::new(rule_35::Rule35::new())])),
+            (36, PrefixDisambiguator::new(vec![Box::new(rule_36::Rule36::new())])),
+            (37, PrefixDisambiguator::new(vec![Box::new(rule_37::Rule37::new())])),
+            (38, PrefixDisambiguator::new(vec![Box::new(rule_38::Rule38::new())])),
+            (39, PrefixDisambiguator::new(vec![Box::new(rule_39::Rule39::new())])),
+            (40, PrefixDisambiguator::new(vec![Box::new(rule_40::Rule40::new())])),
+            (41, PrefixDisambiguator::new(vec![Box::new(rule_41::Rule41::new())])),
+        ]
+    }
+}
+
+impl Default for VisitorConfiguration {
+    fn default() -> Self {
+        Self::for_algorithm(Algorithm::default())
+    }
+}
+
+#[cfg(test)]
+mod visitor_configuration_test {
+    use super::*;
+
+    #[test]
+    fn should_validate_default_configuration() {
+        assert_eq!(VisitorConfiguration::default().validate().is_ok(), true);
+    }
+
+    #[test]
+    fn should_configure_algorithm() {
+        let configuration = VisitorConfiguration::for_algorithm(Algorithm::NaziefAdriani);
+        assert_eq!(configuration.prefix_visitors[0].get_visitor_name(), "DontRemoveDisallowedConfix");
+        assert_eq!(configuration.prefix_visitors.len(), 2 + 32);
+        assert_eq!(configuration.validate().is_ok(), true);
+
+        let configuration = VisitorConfiguration::for_algorithm(Algorithm::EnhancedConfixStripping);
+        assert_eq!(configuration.prefix_visitors[0].get_visitor_name(), "RemovePlainPrefix");
+        assert_eq!(configuration.prefix_visitors.len(), 1 + 35);
+
+        let configuration = VisitorConfiguration::for_algorithm(Algorithm::ModifiedEnhancedConfixStripping);
+        assert_eq!(configuration.prefix_visitors.len(), 1 + 40);
+    }
+
+    #[test]
+    fn should_route_visitor_by_type() {
+        let mut configuration = VisitorConfiguration::empty();
+        configuration.add_visitor(Box::new(RemoveDerivationalSuffix));
+        configuration.add_visitor(Box::new(DontStemShortWord::new()));
+        configuration.add_visitor(Box::new(RemovePlainPrefix::new()));
+
+        assert_eq!(configuration.get_visitors(VisitorType::GeneralVisitor)[0].get_visitor_name(), "DontStemShortWord");
+        assert_eq!(configuration.get_visitors(VisitorType::PrefixVisitor)[0].get_visitor_name(), "RemovePlainPrefix");
+        assert_eq!(configuration.get_visitors(VisitorType::SuffixVisitor)[0].get_visitor_name(), "RemoveDerivationalSuffix");
+        assert_eq!(configuration.validate().is_ok(), true);
+    }
+
+    #[test]
+    fn should_reject_misplaced_visitor() {
+        let mut configuration = VisitorConfiguration::empty();
+        configuration.suffix_visitors.push(Box::new(RemovePlainPrefix::new()));
+        match configuration.validate() {
+            Err(Error::MisplacedVisitor { visitor_name, visitor_type, phase }) => {
+                assert_eq!(visitor_name, "RemovePlainPrefix");
+                assert_eq!(visitor_type, VisitorType::PrefixVisitor);
+                assert_eq!(phase, VisitorType::SuffixVisitor);
+            },
+            _ => panic!("misplaced visitor is accepted"),
+        }
+    }
+}
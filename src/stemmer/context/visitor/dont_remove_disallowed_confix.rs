@@ -0,0 +1,101 @@
+use crate::stemmer::context::Context;
+use crate::stemmer::context::removal::AffixType;
+use crate::stemmer::context::visitor::{Visitor, VisitorResult, VisitorType};
+
+/// Prefix and derivational suffix pairs that never appear together on a word, e.g. "be-...-i".
+const DISALLOWED_CONFIXES: [(&str, &str); 8] = [
+    ("be", "i"),
+    ("di", "an"),
+    ("ke", "i"),
+    ("ke", "kan"),
+    ("me", "an"),
+    ("se", "i"),
+    ("se", "kan"),
+    ("te", "an"),
+];
+
+/// Stops the stemming process before removing a prefix that forms a disallowed confix
+/// with the derivational suffix removed earlier, as in Nazief & Adriani.
+pub struct DontRemoveDisallowedConfix;
+
+impl DontRemoveDisallowedConfix {
+    fn is_disallowed(&self, word: &str, suffix: &str) -> bool {
+        DISALLOWED_CONFIXES.iter()
+            .any(|(disallowed_prefix, disallowed_suffix)| word.starts_with(disallowed_prefix) && suffix == *disallowed_suffix)
+    }
+}
+
+impl Visitor for DontRemoveDisallowedConfix {
+    fn get_visitor_type(&self) -> VisitorType {
+        VisitorType::PrefixVisitor
+    }
+
+    fn visit(&self, context: &mut Context) -> VisitorResult {
+        // only the outermost prefix forms a confix with the suffix
+        if context.removal_list.iter().any(|removal| removal.get_affix_type() == AffixType::DerivationalPrefix) {
+            return VisitorResult::DoNothing;
+        }
+        let suffix_removal = context.removal_list.iter()
+            .rfind(|removal| removal.get_affix_type() == AffixType::DerivationalSuffix);
+        match suffix_removal {
+            Some(removal) if self.is_disallowed(&context.current_word, removal.get_removed_part()) => VisitorResult::StopProcess,
+            _ => VisitorResult::DoNothing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod dont_remove_disallowed_confix_test {
+    use crate::dictionary::Dictionary;
+    use crate::stemmer::context::removal::Removal;
+    use super::*;
+
+    #[test]
+    fn should_return_visitor_type() {
+        let object = DontRemoveDisallowedConfix;
+        assert_eq!(object.get_visitor_type(), VisitorType::PrefixVisitor);
+    }
+
+    #[test]
+    fn should_aware_disallowed_confix() {
+        let object = DontRemoveDisallowedConfix;
+        assert_eq!(object.is_disallowed("berhenti", "i"), true);
+        assert_eq!(object.is_disallowed("ketahu", "kan"), true);
+        assert_eq!(object.is_disallowed("ketahu", "an"), false);
+        assert_eq!(object.is_disallowed("dimain", "kan"), false);
+    }
+
+    #[test]
+    fn should_stop_process_on_disallowed_confix() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("dibuatan", &dictionary, None);
+        context.removal_list.push(Removal::new("RemoveDerivationalSuffix", "dibuatan", "dibuat", AffixType::DerivationalSuffix));
+        context.current_word = String::from("dibuat");
+
+        let result = DontRemoveDisallowedConfix.visit(&mut context);
+        assert_eq!(result, VisitorResult::StopProcess);
+    }
+
+    #[test]
+    fn should_do_nothing_on_allowed_confix() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("dimainkan", &dictionary, None);
+        context.removal_list.push(Removal::new("RemoveDerivationalSuffix", "dimainkan", "dimain", AffixType::DerivationalSuffix));
+        context.current_word = String::from("dimain");
+
+        let result = DontRemoveDisallowedConfix.visit(&mut context);
+        assert_eq!(result, VisitorResult::DoNothing);
+    }
+
+    #[test]
+    fn should_do_nothing_after_prefix_is_removed() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("berkeadilan", &dictionary, None);
+        context.removal_list.push(Removal::new("RemoveDerivationalSuffix", "berkeadilan", "berkeadil", AffixType::DerivationalSuffix));
+        context.removal_list.push(Removal::new("PrefixDisambiguator", "berkeadil", "keadil", AffixType::DerivationalPrefix));
+        context.current_word = String::from("keadil");
+
+        let result = DontRemoveDisallowedConfix.visit(&mut context);
+        assert_eq!(result, VisitorResult::DoNothing);
+    }
+}
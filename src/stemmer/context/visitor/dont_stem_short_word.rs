@@ -1,14 +1,20 @@
 use crate::stemmer::context::Context;
 use crate::stemmer::context::visitor::{Visitor, VisitorResult, VisitorType};
 
-pub struct DontStemShortWord;
+/// Default maximum length of a word that is not stemmed
+pub const DEFAULT_SHORT_WORD_THRESHOLD: usize = 3;
+
+/// Stops the stemming process on short words, e.g. "iya" or "aku"
+pub struct DontStemShortWord {
+    threshold: usize,
+}
 
 impl Visitor for DontStemShortWord {
     fn get_visitor_type(&self) -> VisitorType {
         VisitorType::GeneralVisitor
     }
 
-    fn visit(&self, context: &Context) -> VisitorResult {
+    fn visit(&self, context: &mut Context) -> VisitorResult {
         if self.is_short_word(&(context.current_word)) {
             return VisitorResult::StopProcess
         }
@@ -17,8 +23,23 @@ impl Visitor for DontStemShortWord {
 }
 
 impl DontStemShortWord {
+    pub fn new() -> Self {
+        Self::with_threshold(DEFAULT_SHORT_WORD_THRESHOLD)
+    }
+
+    /// Words with at most `threshold` bytes are not stemmed
+    pub fn with_threshold(threshold: usize) -> Self {
+        Self { threshold }
+    }
+
     fn is_short_word(&self, word: &str) -> bool {
-        word.len() <= 3
+        word.len() <= self.threshold
+    }
+}
+
+impl Default for DontStemShortWord {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -29,13 +50,13 @@ mod dont_stem_short_word_test {
 
     #[test]
     fn should_return_visitor_type() {
-        let object = DontStemShortWord;
+        let object = DontStemShortWord::new();
         assert_eq!(object.get_visitor_type(), VisitorType::GeneralVisitor);
     }
 
     #[test]
     fn should_aware_short_word() {
-        let object = DontStemShortWord;
+        let object = DontStemShortWord::new();
         assert_eq!(object.is_short_word(""), true);
         assert_eq!(object.is_short_word("a"), true);
         assert_eq!(object.is_short_word("ay"), true);
@@ -44,19 +65,26 @@ mod dont_stem_short_word_test {
 
     #[test]
     fn should_aware_long_word() {
-        let object = DontStemShortWord;
+        let object = DontStemShortWord::new();
         assert_eq!(object.is_short_word("ayam"), false);
         assert_eq!(object.is_short_word("kucing"), false);
         assert_eq!(object.is_short_word("gajah"), false);
     }
 
+    #[test]
+    fn should_aware_custom_threshold() {
+        let object = DontStemShortWord::with_threshold(4);
+        assert_eq!(object.is_short_word("ayam"), true);
+        assert_eq!(object.is_short_word("gajah"), false);
+    }
+
     #[test]
     fn short_word_should_return_stop_process() {
         let dictionary = Dictionary::new();
         let mut context = Context::new("iya", &dictionary, None);
 
-        let object = DontStemShortWord;
-        let result = object.visit(&context);
+        let object = DontStemShortWord::new();
+        let result = object.visit(&mut context);
         assert_eq!(result, VisitorResult::StopProcess);
     }
 
@@ -65,8 +93,8 @@ mod dont_stem_short_word_test {
         let dictionary = Dictionary::new();
         let mut context = Context::new("kambing", &dictionary, None);
 
-        let object = DontStemShortWord;
-        let result = object.visit(&context);
+        let object = DontStemShortWord::new();
+        let result = object.visit(&mut context);
         assert_eq!(result, VisitorResult::DoNothing);
     }
 }
\ No newline at end of file
@@ -0,0 +1,95 @@
+use crate::stemmer::confix_stripping::disambiguator::Disambiguator;
+use crate::stemmer::context::Context;
+use crate::stemmer::context::removal::AffixType;
+use crate::stemmer::context::visitor::{Visitor, VisitorResult, VisitorType};
+
+/// Removes a derivational prefix by trying a group of disambiguator rules.
+///
+/// The first candidate found in the dictionary is used. If none of them is a root word,
+/// the last candidate is kept, so the remaining prefixes can still be removed afterwards.
+pub struct PrefixDisambiguator {
+    disambiguators: Vec<Box<dyn Disambiguator>>,
+}
+
+impl PrefixDisambiguator {
+    pub fn new(disambiguators: Vec<Box<dyn Disambiguator>>) -> Self {
+        Self {
+            disambiguators
+        }
+    }
+}
+
+impl Visitor for PrefixDisambiguator {
+    fn get_visitor_type(&self) -> VisitorType {
+        VisitorType::PrefixVisitor
+    }
+
+    fn visit(&self, context: &mut Context) -> VisitorResult {
+        let mut result: Option<String> = None;
+        for disambiguator in &self.disambiguators {
+            if let Some(word) = disambiguator.disambiguate(&context.current_word) {
+                let is_root_word = context.lookup(&word);
+                result = Some(word);
+                if is_root_word {
+                    break;
+                }
+            }
+        }
+
+        if let Some(word) = result {
+            context.remove_affix(self, word, AffixType::DerivationalPrefix);
+        }
+        VisitorResult::DoNothing
+    }
+}
+
+#[cfg(test)]
+mod prefix_disambiguator_test {
+    use crate::dictionary::Dictionary;
+    use crate::stemmer::confix_stripping::disambiguator::rule_1::{Rule1a, Rule1b};
+    use super::*;
+
+    fn object() -> PrefixDisambiguator {
+        PrefixDisambiguator::new(vec![
+            Box::new(Rule1a::new()),
+            Box::new(Rule1b::new()),
+        ])
+    }
+
+    #[test]
+    fn should_return_visitor_type() {
+        assert_eq!(object().get_visitor_type(), VisitorType::PrefixVisitor);
+    }
+
+    #[test]
+    fn should_use_candidate_found_in_dictionary() {
+        let dictionary = Dictionary::from_list(vec!["anak", "ranak"]);
+        let mut context = Context::new("beranak", &dictionary, None);
+        let result = object().visit(&mut context);
+        assert_eq!(result, VisitorResult::DoNothing);
+        assert_eq!(context.current_word, "anak");
+
+        let dictionary = Dictionary::from_list(vec!["rakit"]);
+        let mut context = Context::new("berakit", &dictionary, None);
+        object().visit(&mut context);
+        assert_eq!(context.current_word, "rakit");
+        assert_eq!(context.removal_list.len(), 1);
+        assert_eq!(context.removal_list[0].get_removed_part(), "be");
+    }
+
+    #[test]
+    fn should_use_last_candidate_if_none_is_found_in_dictionary() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("berapi", &dictionary, None);
+        object().visit(&mut context);
+        assert_eq!(context.current_word, "rapi");
+    }
+
+    #[test]
+    fn should_keep_word_if_no_rule_matches() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("bertemu", &dictionary, None);
+        object().visit(&mut context);
+        assert_eq!(context.current_word, "bertemu");
+    }
+}
@@ -0,0 +1,101 @@
+use crate::stemmer::context::Context;
+use crate::stemmer::context::removal::AffixType;
+use crate::stemmer::context::visitor::{Visitor, VisitorResult, VisitorType};
+
+/// Removes the derivational suffix "-i", "-kan" or "-an".
+///
+/// A word ending with "-kan" can also be read as ending with "-an", e.g. "tindakan" is "tindak-an".
+/// Each candidate is checked against the dictionary, and "-kan" is preferred if none of them is a root word.
+pub struct RemoveDerivationalSuffix;
+
+impl RemoveDerivationalSuffix {
+    /// Returns every candidate for the word without its derivational suffix, by order of preference
+    fn remove(&self, word: &str) -> Vec<String> {
+        ["kan", "an", "i"].iter()
+            .filter_map(|suffix| word.strip_suffix(suffix))
+            .map(|candidate| candidate.to_string())
+            .collect()
+    }
+}
+
+impl Visitor for RemoveDerivationalSuffix {
+    fn get_visitor_type(&self) -> VisitorType {
+        VisitorType::SuffixVisitor
+    }
+
+    fn visit(&self, context: &mut Context) -> VisitorResult {
+        let candidates = self.remove(&context.current_word);
+        let root_word = candidates.iter().find(|candidate| context.lookup(candidate));
+        if let Some(word) = root_word.or(candidates.first()) {
+            context.remove_affix(self, word.clone(), AffixType::DerivationalSuffix);
+        }
+        VisitorResult::DoNothing
+    }
+}
+
+#[cfg(test)]
+mod remove_derivational_suffix_test {
+    use crate::dictionary::Dictionary;
+    use super::*;
+
+    #[test]
+    fn should_return_visitor_type() {
+        let object = RemoveDerivationalSuffix;
+        assert_eq!(object.get_visitor_type(), VisitorType::SuffixVisitor);
+    }
+
+    #[test]
+    fn should_return_candidates() {
+        let object = RemoveDerivationalSuffix;
+        assert_eq!(object.remove("mainkan"), vec!["main", "maink"]);
+        assert_eq!(object.remove("makanan"), vec!["makan"]);
+        assert_eq!(object.remove("sakiti"), vec!["sakit"]);
+        assert_eq!(object.remove("buku"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn should_remove_kan() {
+        let dictionary = Dictionary::from_list(vec!["main"]);
+        let mut context = Context::new("mainkan", &dictionary, None);
+
+        let result = RemoveDerivationalSuffix.visit(&mut context);
+        assert_eq!(result, VisitorResult::DoNothing);
+        assert_eq!(context.current_word, "main");
+    }
+
+    #[test]
+    fn should_remove_an_if_it_is_the_root_word() {
+        let dictionary = Dictionary::from_list(vec!["tindak"]);
+        let mut context = Context::new("tindakan", &dictionary, None);
+
+        RemoveDerivationalSuffix.visit(&mut context);
+        assert_eq!(context.current_word, "tindak");
+    }
+
+    #[test]
+    fn should_prefer_kan_if_no_root_word_is_found() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("tindakan", &dictionary, None);
+
+        RemoveDerivationalSuffix.visit(&mut context);
+        assert_eq!(context.current_word, "tinda");
+    }
+
+    #[test]
+    fn should_remove_i() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("sakiti", &dictionary, None);
+
+        RemoveDerivationalSuffix.visit(&mut context);
+        assert_eq!(context.current_word, "sakit");
+    }
+
+    #[test]
+    fn should_keep_word_without_derivational_suffix() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("buku", &dictionary, None);
+
+        RemoveDerivationalSuffix.visit(&mut context);
+        assert_eq!(context.current_word, "buku");
+    }
+}
@@ -0,0 +1,86 @@
+use regex::Regex;
+use crate::stemmer::context::Context;
+use crate::stemmer::context::removal::AffixType;
+use crate::stemmer::context::visitor::{Visitor, VisitorResult, VisitorType};
+
+/// Removes the inflectional particle "-lah", "-kah", "-tah" or "-pun".
+pub struct RemoveInflectionalParticle {
+    regex: Regex,
+}
+
+impl RemoveInflectionalParticle {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"-*(lah|kah|tah|pun)$").unwrap(),
+        }
+    }
+
+    fn remove(&self, word: &str) -> String {
+        self.regex.replace(word, "").to_string()
+    }
+}
+
+impl Default for RemoveInflectionalParticle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Visitor for RemoveInflectionalParticle {
+    fn get_visitor_type(&self) -> VisitorType {
+        VisitorType::SuffixVisitor
+    }
+
+    fn visit(&self, context: &mut Context) -> VisitorResult {
+        let result = self.remove(&context.current_word);
+        context.remove_affix(self, result, AffixType::Particle);
+        VisitorResult::DoNothing
+    }
+}
+
+#[cfg(test)]
+mod remove_inflectional_particle_test {
+    use crate::dictionary::Dictionary;
+    use super::*;
+
+    #[test]
+    fn should_return_visitor_type() {
+        let object = RemoveInflectionalParticle::new();
+        assert_eq!(object.get_visitor_type(), VisitorType::SuffixVisitor);
+    }
+
+    #[test]
+    fn should_remove_particle() {
+        let object = RemoveInflectionalParticle::new();
+        assert_eq!(object.remove("bacalah"), "baca");
+        assert_eq!(object.remove("apakah"), "apa");
+        assert_eq!(object.remove("apatah"), "apa");
+        assert_eq!(object.remove("bukupun"), "buku");
+        assert_eq!(object.remove("buku-pun"), "buku");
+    }
+
+    #[test]
+    fn should_remove_only_the_particle_of_stacked_suffix() {
+        let object = RemoveInflectionalParticle::new();
+        assert_eq!(object.remove("bukunyalah"), "bukunya");
+        assert_eq!(object.remove("pinjamkanlah"), "pinjamkan");
+    }
+
+    #[test]
+    fn should_keep_word_without_particle() {
+        let object = RemoveInflectionalParticle::new();
+        assert_eq!(object.remove("buku"), "buku");
+        assert_eq!(object.remove("bukunya"), "bukunya");
+    }
+
+    #[test]
+    fn should_change_current_word() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("bacalah", &dictionary, None);
+
+        let object = RemoveInflectionalParticle::new();
+        let result = object.visit(&mut context);
+        assert_eq!(result, VisitorResult::DoNothing);
+        assert_eq!(context.current_word, "baca");
+    }
+}
@@ -0,0 +1,84 @@
+use regex::Regex;
+use crate::stemmer::context::Context;
+use crate::stemmer::context::removal::AffixType;
+use crate::stemmer::context::visitor::{Visitor, VisitorResult, VisitorType};
+
+/// Removes the inflectional possessive pronoun "-ku", "-mu" or "-nya".
+pub struct RemoveInflectionalPossessivePronoun {
+    regex: Regex,
+}
+
+impl RemoveInflectionalPossessivePronoun {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"-*(ku|mu|nya)$").unwrap(),
+        }
+    }
+
+    fn remove(&self, word: &str) -> String {
+        self.regex.replace(word, "").to_string()
+    }
+}
+
+impl Default for RemoveInflectionalPossessivePronoun {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Visitor for RemoveInflectionalPossessivePronoun {
+    fn get_visitor_type(&self) -> VisitorType {
+        VisitorType::SuffixVisitor
+    }
+
+    fn visit(&self, context: &mut Context) -> VisitorResult {
+        let result = self.remove(&context.current_word);
+        context.remove_affix(self, result, AffixType::PossessivePronoun);
+        VisitorResult::DoNothing
+    }
+}
+
+#[cfg(test)]
+mod remove_inflectional_possessive_pronoun_test {
+    use crate::dictionary::Dictionary;
+    use super::*;
+
+    #[test]
+    fn should_return_visitor_type() {
+        let object = RemoveInflectionalPossessivePronoun::new();
+        assert_eq!(object.get_visitor_type(), VisitorType::SuffixVisitor);
+    }
+
+    #[test]
+    fn should_remove_possessive_pronoun() {
+        let object = RemoveInflectionalPossessivePronoun::new();
+        assert_eq!(object.remove("bukuku"), "buku");
+        assert_eq!(object.remove("bukumu"), "buku");
+        assert_eq!(object.remove("bukunya"), "buku");
+        assert_eq!(object.remove("kucing-ku"), "kucing");
+    }
+
+    #[test]
+    fn should_remove_only_the_possessive_pronoun_of_stacked_suffix() {
+        let object = RemoveInflectionalPossessivePronoun::new();
+        assert_eq!(object.remove("pinjamkannya"), "pinjamkan");
+        assert_eq!(object.remove("bukunyalah"), "bukunyalah");
+    }
+
+    #[test]
+    fn should_keep_word_without_possessive_pronoun() {
+        let object = RemoveInflectionalPossessivePronoun::new();
+        assert_eq!(object.remove("buka"), "buka");
+    }
+
+    #[test]
+    fn should_change_current_word() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("bukunya", &dictionary, None);
+
+        let object = RemoveInflectionalPossessivePronoun::new();
+        let result = object.visit(&mut context);
+        assert_eq!(result, VisitorResult::DoNothing);
+        assert_eq!(context.current_word, "buku");
+    }
+}
@@ -0,0 +1,99 @@
+use regex::Regex;
+use crate::stemmer::context::Context;
+use crate::stemmer::context::removal::AffixType;
+use crate::stemmer::context::visitor::{Visitor, VisitorResult, VisitorType};
+
+/// Removes the plain derivational prefix "di-", "ke-" or "se-".
+///
+/// Unlike the other prefixes, these do not change with the first letter of the root word.
+pub struct RemovePlainPrefix {
+    regex: Regex,
+}
+
+impl RemovePlainPrefix {
+    pub fn new() -> Self {
+        Self {
+            regex: Regex::new(r"^(di|ke|se)").unwrap(),
+        }
+    }
+
+    fn remove(&self, word: &str) -> String {
+        self.regex.replace(word, "").to_string()
+    }
+}
+
+impl Default for RemovePlainPrefix {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Visitor for RemovePlainPrefix {
+    fn get_visitor_type(&self) -> VisitorType {
+        VisitorType::PrefixVisitor
+    }
+
+    fn visit(&self, context: &mut Context) -> VisitorResult {
+        let result = self.remove(&context.current_word);
+        context.remove_affix(self, result, AffixType::DerivationalPrefix);
+        VisitorResult::DoNothing
+    }
+}
+
+#[cfg(test)]
+mod remove_plain_prefix_test {
+    use crate::dictionary::Dictionary;
+    use super::*;
+
+    #[test]
+    fn should_return_visitor_type() {
+        let object = RemovePlainPrefix::new();
+        assert_eq!(object.get_visitor_type(), VisitorType::PrefixVisitor);
+    }
+
+    #[test]
+    fn should_remove_plain_prefix() {
+        let object = RemovePlainPrefix::new();
+        assert_eq!(object.remove("dibaca"), "baca");
+        assert_eq!(object.remove("kekasih"), "kasih");
+        assert_eq!(object.remove("sebuah"), "buah");
+    }
+
+    #[test]
+    fn should_remove_only_one_prefix() {
+        let object = RemovePlainPrefix::new();
+        assert_eq!(object.remove("diperbaiki"), "perbaiki");
+        assert_eq!(object.remove("disebut"), "sebut");
+    }
+
+    #[test]
+    fn should_keep_word_without_plain_prefix() {
+        let object = RemovePlainPrefix::new();
+        assert_eq!(object.remove("membaca"), "membaca");
+        assert_eq!(object.remove("adik"), "adik");
+    }
+
+    #[test]
+    fn should_change_current_word() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("dibaca", &dictionary, None);
+
+        let object = RemovePlainPrefix::new();
+        let result = object.visit(&mut context);
+        assert_eq!(result, VisitorResult::DoNothing);
+        assert_eq!(context.current_word, "baca");
+        assert_eq!(context.removal_list.len(), 1);
+        assert_eq!(context.removal_list[0].get_removed_part(), "di");
+        assert_eq!(context.removal_list[0].get_visitor_name(), "RemovePlainPrefix");
+    }
+
+    #[test]
+    fn should_not_record_removal_without_plain_prefix() {
+        let dictionary = Dictionary::new();
+        let mut context = Context::new("membaca", &dictionary, None);
+
+        RemovePlainPrefix::new().visit(&mut context);
+        assert_eq!(context.current_word, "membaca");
+        assert_eq!(context.removal_list.len(), 0);
+    }
+}
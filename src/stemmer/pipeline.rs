@@ -0,0 +1,159 @@
+use std::sync::{Arc, OnceLock};
+use crate::error::Result;
+use crate::stemmer::algorithm::Algorithm;
+use crate::stemmer::confix_stripping::precedence_adjustment::PrecedenceAdjustment;
+use crate::stemmer::context::visitor::{Visitor, VisitorConfiguration};
+use crate::stemmer::stemming_mode::StemmingMode;
+
+/// Immutable set of visitors and compiled rules used by the stemming process.
+///
+/// A pipeline is built once and then borrowed by every `Context`,
+/// so the visitors and their regexes are not rebuilt for each stemmed word.
+///
+/// Visitors run in three phases, each one short-circuits as soon as a visitor stops the process
+/// or the current word is a root word:
+/// - general visitors run once, in order, before any affix is removed.
+/// - suffix visitors run once, in order.
+/// - prefix visitors run in order until one of them removes a prefix, up to three times.
+pub struct Pipeline {
+    general_visitors: Vec<Box<dyn Visitor>>,
+    prefix_visitors: Vec<Box<dyn Visitor>>,
+    suffix_visitors: Vec<Box<dyn Visitor>>,
+    precedence_adjustment: PrecedenceAdjustment,
+    algorithm: Algorithm,
+    mode: StemmingMode,
+}
+
+impl Pipeline {
+    /// Initialize pipeline with the given visitors and precedence adjustment rules, following the steps
+    /// of the given mode and algorithm, or returns an error if a visitor is registered in the wrong phase.
+    ///
+    /// In `StemmingMode::Light`, the visitors are run without the precedence adjustment
+    /// and the suffix restoration loop of the algorithm.
+    ///
+    /// Use `Pipeline::default` for the default visitors and rules, or `StemmerBuilder` to derive them
+    /// from an algorithm and a mode.
+    pub fn try_with_mode(mode: StemmingMode, algorithm: Algorithm, visitor_configuration: VisitorConfiguration, precedence_adjustment: PrecedenceAdjustment) -> Result<Self> {
+        visitor_configuration.validate()?;
+        Ok(Self {
+            general_visitors: visitor_configuration.general_visitors,
+            prefix_visitors: visitor_configuration.prefix_visitors,
+            suffix_visitors: visitor_configuration.suffix_visitors,
+            precedence_adjustment,
+            algorithm,
+            mode,
+        })
+    }
+
+    /// Returns the default pipeline, which is built once and shared by the whole process
+    pub fn shared() -> Arc<Pipeline> {
+        Arc::clone(Self::shared_default())
+    }
+
+    pub(crate) fn shared_default() -> &'static Arc<Pipeline> {
+        static DEFAULT_PIPELINE: OnceLock<Arc<Pipeline>> = OnceLock::new();
+        DEFAULT_PIPELINE.get_or_init(|| Arc::new(Pipeline::default()))
+    }
+
+    pub fn get_general_visitors(&self) -> &[Box<dyn Visitor>] {
+        &self.general_visitors
+    }
+
+    pub fn get_prefix_visitors(&self) -> &[Box<dyn Visitor>] {
+        &self.prefix_visitors
+    }
+
+    pub fn get_suffix_visitors(&self) -> &[Box<dyn Visitor>] {
+        &self.suffix_visitors
+    }
+
+    pub fn get_precedence_adjustment(&self) -> &PrecedenceAdjustment {
+        &self.precedence_adjustment
+    }
+
+    pub fn get_algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    pub fn get_mode(&self) -> StemmingMode {
+        self.mode
+    }
+
+    /// Returns true if prefixes are removed first on words satisfying the precedence adjustment
+    pub(crate) fn uses_precedence_adjustment(&self) -> bool {
+        self.mode == StemmingMode::Full && self.algorithm.uses_precedence_adjustment()
+    }
+
+    /// Returns true if the removed suffixes are restored when no root word is found
+    pub(crate) fn uses_suffix_restoration(&self) -> bool {
+        self.mode == StemmingMode::Full && self.algorithm.uses_suffix_restoration()
+    }
+}
+
+impl Default for Pipeline {
+    /// Initialize pipeline with the default visitors and rules
+    fn default() -> Self {
+        let algorithm = Algorithm::default();
+        let visitor_configuration = VisitorConfiguration::for_algorithm(algorithm);
+        match Self::try_with_mode(StemmingMode::Full, algorithm, visitor_configuration, PrecedenceAdjustment::new()) {
+            Ok(pipeline) => pipeline,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod pipeline_test {
+    use super::*;
+
+    #[test]
+    fn should_build_default_pipeline() {
+        let pipeline = Pipeline::default();
+        assert_eq!(pipeline.get_general_visitors().len(), 1);
+        assert_eq!(pipeline.get_prefix_visitors().len(), 41);
+        assert_eq!(pipeline.get_suffix_visitors().len(), 3);
+        assert_eq!(pipeline.get_precedence_adjustment().is_satisfied_by("benarkanlah"), true);
+        assert_eq!(pipeline.get_algorithm(), Algorithm::ModifiedEnhancedConfixStripping);
+    }
+
+    #[test]
+    fn should_build_pipeline_for_algorithm() {
+        let algorithm = Algorithm::NaziefAdriani;
+        let configuration = VisitorConfiguration::for_algorithm(algorithm);
+        let pipeline = Pipeline::try_with_mode(StemmingMode::Full, algorithm, configuration, PrecedenceAdjustment::new()).unwrap();
+        assert_eq!(pipeline.get_algorithm(), Algorithm::NaziefAdriani);
+        assert_eq!(pipeline.get_prefix_visitors().len(), 34);
+    }
+
+    #[test]
+    fn should_build_light_pipeline() {
+        let configuration = VisitorConfiguration::light();
+        let pipeline = Pipeline::try_with_mode(StemmingMode::Light, Algorithm::default(), configuration, PrecedenceAdjustment::new()).unwrap();
+        assert_eq!(pipeline.get_mode(), StemmingMode::Light);
+        assert_eq!(pipeline.get_prefix_visitors().len(), 0);
+        assert_eq!(pipeline.get_suffix_visitors().len(), 2);
+        assert_eq!(pipeline.uses_precedence_adjustment(), false);
+        assert_eq!(pipeline.uses_suffix_restoration(), false);
+    }
+
+    #[test]
+    fn should_reject_misplaced_visitor() {
+        let mut configuration = VisitorConfiguration::default();
+        let visitor = configuration.suffix_visitors.remove(0);
+        configuration.general_visitors.push(visitor);
+        let result = Pipeline::try_with_mode(StemmingMode::Full, Algorithm::default(), configuration, PrecedenceAdjustment::new());
+        let error = result.err().unwrap();
+        assert_eq!(error.to_string(), "RemoveInflectionalParticle is a SuffixVisitor, but is registered as GeneralVisitor");
+    }
+
+    #[test]
+    fn should_share_default_pipeline() {
+        assert_eq!(Arc::ptr_eq(&Pipeline::shared(), &Pipeline::shared()), true);
+    }
+
+    #[test]
+    fn should_be_shared_across_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Pipeline>();
+    }
+}
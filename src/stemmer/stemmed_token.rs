@@ -0,0 +1,20 @@
+use std::ops::Range;
+
+/// A word of the stemmed text, along with its stem
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StemmedToken {
+    /// The word as written in the input text
+    pub surface: String,
+    /// Byte range of the word in the input text
+    pub span: Range<usize>,
+    pub stem: String,
+    /// Removed derivational prefixes, outermost first
+    pub removed_prefixes: Vec<String>,
+    /// Removed suffixes, outermost first
+    pub removed_suffixes: Vec<String>,
+    /// True if the stem is found in the dictionary.
+    ///
+    /// False if no root word is found, and the stem is the word itself or the result of the rule-based fallback.
+    pub is_dictionary_confirmed: bool,
+}
@@ -0,0 +1,547 @@
+use std::sync::Arc;
+use crate::dictionary::Dictionary;
+use crate::error::{Error, Result};
+use crate::lexicon::Lexicon;
+use crate::normalizer::Normalizer;
+use crate::stemmer::algorithm::Algorithm;
+use crate::stemmer::Stemmer;
+use crate::stemmer::confix_stripping::precedence_adjustment::PrecedenceAdjustment;
+use crate::stemmer::context::visitor::{Visitor, VisitorConfiguration, VisitorType};
+use crate::stemmer::context::visitor::dont_stem_short_word::DontStemShortWord;
+use crate::stemmer::pipeline::Pipeline;
+use crate::stemmer::stemming_mode::StemmingMode;
+use crate::stemmer::tala_stemmer::TalaStemmer;
+
+/// A change of the visitors, applied when the stemmer is built
+type VisitorChange = Box<dyn FnOnce(&mut VisitorConfiguration) -> Result<()> + Send>;
+
+/// Builds a `Stemmer` with a custom dictionary and visitor pipeline.
+///
+/// The builder starts from the default visitors and precedence adjustment rules.
+/// Visitors are grouped by the phase they run in, given as a `VisitorType`.
+///
+/// Visitor changes are recorded, and applied on the visitors of the algorithm and mode when the stemmer is built,
+/// so the order of the calls to `algorithm`, `mode` and `short_word_threshold` does not matter.
+///
+/// # Examples
+///
+/// ```
+/// use rustrawi::dictionary::Dictionary;
+/// use rustrawi::stemmer::Stemmer;
+/// use rustrawi::stemmer::context::visitor::VisitorType;
+///
+/// // only remove suffixes
+/// let stemmer = Stemmer::builder()
+///     .dictionary(Dictionary::from_list(vec!["baca"]))
+///     .visitors(VisitorType::PrefixVisitor, vec![])
+///     .build();
+/// assert_eq!(stemmer.stem(String::from("bacakan membacakan")), "baca membacakan");
+/// ```
+pub struct StemmerBuilder {
+    dictionary: Option<Box<dyn Lexicon>>,
+    visitor_changes: Vec<VisitorChange>,
+    short_word_threshold: Option<usize>,
+    precedence_adjustment: PrecedenceAdjustment,
+    algorithm: Algorithm,
+    mode: StemmingMode,
+    fallback: Option<TalaStemmer>,
+    normalizer: Normalizer,
+}
+
+impl StemmerBuilder {
+    pub fn new() -> Self {
+        Self {
+            dictionary: None,
+            visitor_changes: vec![],
+            short_word_threshold: None,
+            precedence_adjustment: PrecedenceAdjustment::new(),
+            algorithm: Algorithm::default(),
+            mode: StemmingMode::default(),
+            fallback: None,
+            normalizer: Normalizer::new(),
+        }
+    }
+
+    /// Sets the root word dictionary.
+    ///
+    /// The bundled dictionary is used if none is given, or an empty one
+    /// when the `bundled-dictionary` feature is disabled.
+    pub fn dictionary(mut self, dictionary: Dictionary) -> Self {
+        self.dictionary = Some(Box::new(dictionary));
+        self
+    }
+
+    /// Sets the root word store, e.g. an `FstDictionary`, instead of a `Dictionary`.
+    pub fn lexicon(mut self, lexicon: impl Lexicon + 'static) -> Self {
+        self.dictionary = Some(Box::new(lexicon));
+        self
+    }
+
+    /// Sets the variant of the stemming algorithm.
+    ///
+    /// Its visitors are the ones added, removed or reordered by the other calls, made before or after this one.
+    pub fn algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Sets how much of a word is removed, see `StemmingMode`.
+    ///
+    /// Its visitors are the ones added, removed or reordered by the other calls, made before or after this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::stemmer::Stemmer;
+    /// use rustrawi::stemmer::stemming_mode::StemmingMode;
+    /// let stemmer = Stemmer::builder()
+    ///     .dictionary(Dictionary::from_list(vec!["bangun", "bangunan", "rumah"]))
+    ///     .mode(StemmingMode::Light)
+    ///     .build();
+    /// assert_eq!(stemmer.stem(String::from("pembangunan bangunan rumahnya")), "pembangunan bangunan rumah");
+    /// ```
+    pub fn mode(mut self, mode: StemmingMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Appends a visitor at the end of the phase matching its declared `VisitorType`
+    pub fn route_visitor(self, visitor: Box<dyn Visitor>) -> Self {
+        self.change_visitors(move |visitor_configuration| {
+            visitor_configuration.add_visitor(visitor);
+            Ok(())
+        })
+    }
+
+    /// Replaces every visitor of the given phase
+    pub fn visitors(self, phase: VisitorType, visitors: Vec<Box<dyn Visitor>>) -> Self {
+        self.change_visitors(move |visitor_configuration| {
+            *visitor_configuration.get_visitors_mut(phase) = visitors;
+            Ok(())
+        })
+    }
+
+    /// Appends a visitor at the end of the given phase
+    pub fn add_visitor(self, phase: VisitorType, visitor: Box<dyn Visitor>) -> Self {
+        self.change_visitors(move |visitor_configuration| {
+            visitor_configuration.get_visitors_mut(phase).push(visitor);
+            Ok(())
+        })
+    }
+
+    /// Inserts a visitor at `index` of the given phase.
+    ///
+    /// The stemmer build fails with `Error::InvalidVisitorIndex`
+    /// if `index` is greater than the number of visitors in the phase.
+    pub fn insert_visitor(self, phase: VisitorType, index: usize, visitor: Box<dyn Visitor>) -> Self {
+        self.change_visitors(move |visitor_configuration| {
+            let visitors = visitor_configuration.get_visitors_mut(phase);
+            if index > visitors.len() {
+                return Err(Error::InvalidVisitorIndex { phase, index });
+            }
+            visitors.insert(index, visitor);
+            Ok(())
+        })
+    }
+
+    /// Removes every visitor of the given phase named `visitor_name`, see `Visitor::get_visitor_name`
+    pub fn remove_visitor(self, phase: VisitorType, visitor_name: &str) -> Self {
+        let visitor_name = visitor_name.to_string();
+        self.change_visitors(move |visitor_configuration| {
+            visitor_configuration.get_visitors_mut(phase).retain(|visitor| visitor.get_visitor_name() != visitor_name);
+            Ok(())
+        })
+    }
+
+    /// Moves the visitor at index `from` of the given phase to index `to`.
+    ///
+    /// The stemmer build fails with `Error::InvalidVisitorIndex` if either index is out of bounds.
+    pub fn move_visitor(self, phase: VisitorType, from: usize, to: usize) -> Self {
+        self.change_visitors(move |visitor_configuration| {
+            let visitors = visitor_configuration.get_visitors_mut(phase);
+            if let Some(index) = [from, to].into_iter().find(|index| *index >= visitors.len()) {
+                return Err(Error::InvalidVisitorIndex { phase, index });
+            }
+            let visitor = visitors.remove(from);
+            visitors.insert(to, visitor);
+            Ok(())
+        })
+    }
+
+    /// Replaces the rules deciding whether a prefix is removed before a suffix
+    pub fn precedence_adjustment(mut self, precedence_adjustment: PrecedenceAdjustment) -> Self {
+        self.precedence_adjustment = precedence_adjustment;
+        self
+    }
+
+    /// Stems words with the given rule-based stemmer when no root word is found in the dictionary,
+    /// instead of returning them as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::stemmer::Stemmer;
+    /// use rustrawi::stemmer::tala_stemmer::TalaStemmer;
+    /// let stemmer = Stemmer::builder()
+    ///     .dictionary(Dictionary::from_list(vec!["baca"]))
+    ///     .fallback(TalaStemmer::new())
+    ///     .build();
+    /// assert_eq!(stemmer.stem(String::from("membaca mengupload")), "baca upload");
+    /// ```
+    pub fn fallback(mut self, fallback: TalaStemmer) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    /// Replaces the normalizer applied on the text before it is tokenized, see `Normalizer::new` for the default preset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::dictionary::Dictionary;
+    /// use rustrawi::normalizer::Normalizer;
+    /// use rustrawi::normalizer::apostrophe_policy::ApostrophePolicy;
+    /// use rustrawi::normalizer::case_policy::CasePolicy;
+    /// use rustrawi::normalizer::dash_unification::DashUnification;
+    /// use rustrawi::normalizer::strip_diacritics::StripDiacritics;
+    /// use rustrawi::stemmer::Stemmer;
+    /// let normalizer = Normalizer::from(vec![
+    ///     Box::new(StripDiacritics),
+    ///     Box::new(DashUnification),
+    ///     Box::new(ApostrophePolicy::Remove),
+    ///     Box::new(CasePolicy::Lowercase),
+    /// ]);
+    /// let stemmer = Stemmer::builder()
+    ///     .dictionary(Dictionary::from_list(vec!["maaf", "kafe", "buku"]))
+    ///     .normalizer(normalizer)
+    ///     .build();
+    /// assert_eq!(stemmer.stem(String::from("Ma'afkan kafénya, buku–buku")), "maaf kafe buku");
+    /// ```
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Words with at most `threshold` bytes are not stemmed.
+    ///
+    /// Replaces the `DontStemShortWord` general visitor, or adds it first if it was removed,
+    /// after every other visitor change.
+    pub fn short_word_threshold(mut self, threshold: usize) -> Self {
+        self.short_word_threshold = Some(threshold);
+        self
+    }
+
+    /// Builds the stemmer. Its pipeline is used for every stemmed word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a visitor is registered in the wrong phase or at an out of bounds index,
+    /// see `try_build` for the fallible version.
+    pub fn build(self) -> Stemmer {
+        match self.try_build() {
+            Ok(stemmer) => stemmer,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Builds the stemmer, or returns an error if a visitor is registered in the wrong phase
+    /// or at an out of bounds index
+    pub fn try_build(mut self) -> Result<Stemmer> {
+        let visitor_configuration = self.take_visitor_configuration()?;
+        let pipeline = Pipeline::try_with_mode(self.mode, self.algorithm, visitor_configuration, self.precedence_adjustment)?;
+        let dictionary = self.dictionary.unwrap_or_else(|| Box::new(Self::default_dictionary()));
+        Ok(Stemmer {
+            dictionary,
+            pipeline: Arc::new(pipeline),
+            fallback: self.fallback,
+            normalizer: self.normalizer,
+            ..Stemmer::empty()
+        })
+    }
+
+    #[cfg(feature = "bundled-dictionary")]
+    fn default_dictionary() -> Dictionary {
+        Dictionary::bundled()
+    }
+
+    #[cfg(not(feature = "bundled-dictionary"))]
+    fn default_dictionary() -> Dictionary {
+        Dictionary::new()
+    }
+
+    fn change_visitors(mut self, change: impl FnOnce(&mut VisitorConfiguration) -> Result<()> + Send + 'static) -> Self {
+        self.visitor_changes.push(Box::new(change));
+        self
+    }
+
+    /// Returns the visitors of the algorithm and mode, with every recorded change applied in order,
+    /// or the error of the first change that cannot be applied
+    fn take_visitor_configuration(&mut self) -> Result<VisitorConfiguration> {
+        let mut visitor_configuration = match self.mode {
+            StemmingMode::Full => VisitorConfiguration::for_algorithm(self.algorithm),
+            StemmingMode::Light => VisitorConfiguration::light(),
+        };
+        for change in self.visitor_changes.drain(..) {
+            change(&mut visitor_configuration)?;
+        }
+        if let Some(threshold) = self.short_word_threshold {
+            let visitor = Box::new(DontStemShortWord::with_threshold(threshold));
+            let visitor_name = visitor.get_visitor_name();
+            let general_visitors = visitor_configuration.get_visitors_mut(VisitorType::GeneralVisitor);
+            match general_visitors.iter().position(|visitor| visitor.get_visitor_name() == visitor_name) {
+                Some(index) => general_visitors[index] = visitor,
+                None => general_visitors.insert(0, visitor),
+            }
+        }
+        Ok(visitor_configuration)
+    }
+}
+
+impl Default for StemmerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod stemmer_builder_test {
+    use regex::Regex;
+    use super::*;
+    use crate::normalizer::strip_diacritics::StripDiacritics;
+    use crate::stemmer::context::Context;
+    use crate::stemmer::context::derivation::DerivationStep;
+    use crate::stemmer::context::removal::AffixType;
+    use crate::stemmer::context::visitor::VisitorResult;
+
+    /// Removes the colloquial "nge-" prefix
+    struct RemoveNgePrefix;
+
+    impl Visitor for RemoveNgePrefix {
+        fn get_visitor_type(&self) -> VisitorType {
+            VisitorType::PrefixVisitor
+        }
+
+        fn visit(&self, context: &mut Context) -> VisitorResult {
+            if let Some(word) = context.get_current_word().strip_prefix("nge") {
+                let word = word.to_string();
+                context.remove_affix(self, word, AffixType::DerivationalPrefix);
+            }
+            VisitorResult::DoNothing
+        }
+    }
+
+    fn visitor_names(mut builder: StemmerBuilder, phase: VisitorType) -> Vec<&'static str> {
+        builder.take_visitor_configuration().unwrap().get_visitors(phase).iter().map(|visitor| visitor.get_visitor_name()).collect()
+    }
+
+    #[test]
+    fn should_build_with_default_configuration() {
+        let stemmer = StemmerBuilder::new().dictionary(Dictionary::from_list(vec!["bahagia"])).build();
+        assert_eq!(stemmer.len(), 1);
+        assert_eq!(stemmer.stem(String::from("Membahagiakan")), "bahagia");
+    }
+
+    #[test]
+    fn should_add_visitor() {
+        let dictionary = Dictionary::from_list(vec!["gas"]);
+        let stemmer = StemmerBuilder::new()
+            .dictionary(dictionary)
+            .add_visitor(VisitorType::PrefixVisitor, Box::new(RemoveNgePrefix))
+            .build();
+        assert_eq!(stemmer.stem(String::from("ngegas")), "gas");
+    }
+
+    #[test]
+    fn should_insert_move_and_remove_visitor() {
+        let builder = || StemmerBuilder::new()
+            .insert_visitor(VisitorType::PrefixVisitor, 0, Box::new(RemoveNgePrefix));
+        assert_eq!(visitor_names(builder(), VisitorType::PrefixVisitor)[0], "RemoveNgePrefix");
+
+        let moved_builder = || builder().move_visitor(VisitorType::PrefixVisitor, 0, 1);
+        assert_eq!(visitor_names(moved_builder(), VisitorType::PrefixVisitor)[0..2], ["RemovePlainPrefix", "RemoveNgePrefix"]);
+
+        let removed_builder = moved_builder().remove_visitor(VisitorType::PrefixVisitor, "RemoveNgePrefix");
+        assert_eq!(visitor_names(removed_builder, VisitorType::PrefixVisitor).contains(&"RemoveNgePrefix"), false);
+    }
+
+    #[test]
+    fn should_return_error_on_out_of_bounds_index() {
+        let result = StemmerBuilder::new()
+            .visitors(VisitorType::SuffixVisitor, vec![])
+            .insert_visitor(VisitorType::SuffixVisitor, 1, Box::new(RemoveNgePrefix))
+            .try_build();
+        assert_eq!(matches!(result, Err(Error::InvalidVisitorIndex { phase: VisitorType::SuffixVisitor, index: 1 })), true);
+
+        let result = StemmerBuilder::new().move_visitor(VisitorType::GeneralVisitor, 0, 5).try_build();
+        assert_eq!(matches!(result, Err(Error::InvalidVisitorIndex { phase: VisitorType::GeneralVisitor, index: 5 })), true);
+
+        let result = StemmerBuilder::new().move_visitor(VisitorType::GeneralVisitor, 5, 0).try_build();
+        assert_eq!(matches!(result, Err(Error::InvalidVisitorIndex { phase: VisitorType::GeneralVisitor, index: 5 })), true);
+    }
+
+    #[test]
+    fn should_be_sendable() {
+        fn assert_send<T: Send>(_: &T) {}
+        assert_send(&StemmerBuilder::new().add_visitor(VisitorType::PrefixVisitor, Box::new(RemoveNgePrefix)));
+    }
+
+    #[test]
+    fn should_build_with_algorithm() {
+        let stem = |algorithm: Algorithm, word: &str| {
+            let dictionary = Dictionary::from_list(vec!["tolak", "baik", "henti"]);
+            StemmerBuilder::new().dictionary(dictionary).algorithm(algorithm).build().stem(word.to_string())
+        };
+
+        // no rule precedence adjustment
+        assert_eq!(stem(Algorithm::NaziefAdriani, "berbaikan"), "berbaikan");
+        assert_eq!(stem(Algorithm::ConfixStripping, "berbaikan"), "baik");
+
+        // no suffix restoration
+        assert_eq!(stem(Algorithm::ConfixStripping, "penolakan"), "penolakan");
+        assert_eq!(stem(Algorithm::EnhancedConfixStripping, "penolakan"), "tolak");
+
+        // disallowed "be-i" confix
+        assert_eq!(stem(Algorithm::ConfixStripping, "berhenti"), "berhenti");
+        assert_eq!(stem(Algorithm::ModifiedEnhancedConfixStripping, "berhenti"), "henti");
+    }
+
+    #[test]
+    fn should_build_light_stemmer() {
+        let dictionary = Dictionary::from_list(vec!["bangun", "buku", "temu"]);
+        let stemmer = StemmerBuilder::new().dictionary(dictionary).mode(StemmingMode::Light).build();
+        assert_eq!(stemmer.stem(String::from("pembangunan")), "pembangunan");
+        assert_eq!(stemmer.stem(String::from("bukunyalah bukumu")), "buku buku");
+
+        // the result is still checked against the dictionary
+        assert_eq!(stemmer.stem(String::from("bertemu")), "bertemu");
+        // short words are still not stemmed
+        assert_eq!(stemmer.stem(String::from("aku")), "aku");
+    }
+
+    #[test]
+    fn should_keep_mode_on_algorithm_change() {
+        let builder = || StemmerBuilder::new()
+            .mode(StemmingMode::Light)
+            .algorithm(Algorithm::NaziefAdriani);
+        assert_eq!(visitor_names(builder(), VisitorType::PrefixVisitor).len(), 0);
+
+        let builder = builder().mode(StemmingMode::Full);
+        assert_eq!(visitor_names(builder, VisitorType::PrefixVisitor)[0], "DontRemoveDisallowedConfix");
+    }
+
+    #[test]
+    fn should_build_with_normalizer() {
+        let dictionary = Dictionary::from_list(vec!["kafe"]);
+        let stemmer = StemmerBuilder::new().dictionary(dictionary).normalizer(Normalizer::from(vec![Box::new(StripDiacritics)])).build();
+        assert_eq!(stemmer.get_normalizer().get_steps().len(), 1);
+        assert_eq!(stemmer.stem(String::from("kafénya Jakarta")), "kafe Jakarta");
+    }
+
+    #[cfg(feature = "fst")]
+    #[test]
+    fn should_build_with_lexicon() {
+        use crate::dictionary::fst_dictionary::FstDictionary;
+        let dictionary = FstDictionary::from(&Dictionary::from_list(vec!["bahagia"]));
+        let stemmer = StemmerBuilder::new().lexicon(dictionary).build();
+        assert_eq!(stemmer.len(), 1);
+        assert_eq!(stemmer.stem(String::from("Membahagiakan")), "bahagia");
+    }
+
+    #[test]
+    fn should_build_with_fallback() {
+        let dictionary = Dictionary::from_list(vec!["baca", "membaca"]);
+        let stemmer = StemmerBuilder::new().dictionary(dictionary).fallback(TalaStemmer::new()).build();
+        // the dictionary root is preferred over the rule-based one
+        assert_eq!(stemmer.stem(String::from("dibacakan membaca")), "baca membaca");
+        assert_eq!(stemmer.stem(String::from("mengupload didownload")), "upload download");
+
+        let derivation = stemmer.stem_word_explained("mengupload");
+        assert_eq!(derivation.resulting_word, "upload");
+        assert_eq!(derivation.steps.last(), Some(&DerivationStep::RuleBasedFallback { result: String::from("upload") }));
+    }
+
+    #[test]
+    fn should_not_fall_back_by_default() {
+        let stemmer = StemmerBuilder::new().dictionary(Dictionary::from_list(vec!["baca"])).build();
+        assert_eq!(stemmer.stem(String::from("mengupload")), "mengupload");
+    }
+
+    #[test]
+    fn should_route_visitor_by_type() {
+        let builder = StemmerBuilder::new().route_visitor(Box::new(RemoveNgePrefix));
+        assert_eq!(visitor_names(builder, VisitorType::PrefixVisitor).last(), Some(&"RemoveNgePrefix"));
+    }
+
+    #[test]
+    fn should_reject_misplaced_visitor() {
+        let result = StemmerBuilder::new()
+            .dictionary(Dictionary::new())
+            .add_visitor(VisitorType::SuffixVisitor, Box::new(RemoveNgePrefix))
+            .try_build();
+        assert_eq!(matches!(result, Err(crate::error::Error::MisplacedVisitor { .. })), true);
+    }
+
+    #[test]
+    fn should_replace_visitors() {
+        let dictionary = Dictionary::from_list(vec!["baca"]);
+        let stemmer = StemmerBuilder::new()
+            .dictionary(dictionary)
+            .visitors(VisitorType::SuffixVisitor, vec![])
+            .build();
+        assert_eq!(stemmer.stem(String::from("membaca bacakan")), "baca bacakan");
+    }
+
+    #[test]
+    fn should_set_short_word_threshold() {
+        let dictionary = Dictionary::from_list(vec!["ajar"]);
+        let stemmer = StemmerBuilder::new()
+            .dictionary(dictionary)
+            .short_word_threshold(6)
+            .build();
+        assert_eq!(stemmer.stem(String::from("ajarku belajar")), "ajarku ajar");
+    }
+
+    #[test]
+    fn should_add_short_word_visitor_if_removed() {
+        let builder = StemmerBuilder::new()
+            .remove_visitor(VisitorType::GeneralVisitor, "DontStemShortWord")
+            .short_word_threshold(6);
+        assert_eq!(visitor_names(builder, VisitorType::GeneralVisitor), ["DontStemShortWord"]);
+    }
+
+    #[test]
+    fn should_keep_settings_whatever_the_call_order() {
+        let stem = |builder: StemmerBuilder| builder.dictionary(Dictionary::from_list(vec!["ajar", "gasak"])).build()
+            .stem(String::from("ajarku belajar ngegasak"));
+
+        let before = StemmerBuilder::new()
+            .short_word_threshold(6)
+            .add_visitor(VisitorType::PrefixVisitor, Box::new(RemoveNgePrefix))
+            .algorithm(Algorithm::EnhancedConfixStripping)
+            .mode(StemmingMode::Full);
+        let after = StemmerBuilder::new()
+            .mode(StemmingMode::Full)
+            .algorithm(Algorithm::EnhancedConfixStripping)
+            .add_visitor(VisitorType::PrefixVisitor, Box::new(RemoveNgePrefix))
+            .short_word_threshold(6);
+        assert_eq!(stem(before), "ajarku ajar gasak");
+        assert_eq!(stem(after), "ajarku ajar gasak");
+    }
+
+    #[test]
+    fn should_swap_precedence_adjustment() {
+        // "bertemulah" removes the prefix first by default, and stops at "temulah"
+        let dictionary = Dictionary::from_list(vec!["bertemu", "temulah"]);
+        let stemmer = StemmerBuilder::new().dictionary(dictionary).build();
+        assert_eq!(stemmer.stem(String::from("bertemulah")), "temulah");
+
+        let dictionary = Dictionary::from_list(vec!["bertemu", "temulah"]);
+        let stemmer = StemmerBuilder::new()
+            .dictionary(dictionary)
+            .precedence_adjustment(PrecedenceAdjustment::from(vec![Regex::new(r"^$").unwrap()]))
+            .build();
+        assert_eq!(stemmer.stem(String::from("bertemulah")), "bertemu");
+    }
+}
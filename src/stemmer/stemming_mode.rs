@@ -0,0 +1,14 @@
+/// How much of a word is removed by the stemming process
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum StemmingMode {
+    /// Removes inflectional and derivational affixes, following the chosen `Algorithm`.
+    #[default]
+    Full,
+    /// Only removes the inflectional suffixes: particles (-lah, -kah, -tah, -pun)
+    /// and possessive pronouns (-ku, -mu, -nya).
+    ///
+    /// The result is still checked against the dictionary, and short words are still not stemmed,
+    /// so "pembangunan" and "bangunan" are kept apart.
+    Light,
+}
@@ -0,0 +1,298 @@
+use crate::tokenizer::{TokenKind, Tokenizer};
+
+/// Dictionary-free, rule-based stemmer, following the Tala algorithm as implemented by Snowball.
+///
+/// Affixes are removed based only on the spelling of the word and its number of vowels,
+/// so it also stems words missing from the dictionary, e.g. slang or loanwords,
+/// at the cost of being less accurate than the dictionary-based stemmer.
+///
+/// # Reference
+/// - Tala F. Z. (2003) "A Study of Stemming Effects on Information Retrieval in Bahasa Indonesia".
+/// - https://snowballstem.org/algorithms/indonesian/stemmer.html
+///
+/// # Examples
+///
+/// ```
+/// use rustrawi::stemmer::tala_stemmer::TalaStemmer;
+/// let stemmer = TalaStemmer::new();
+/// assert_eq!(stemmer.stem(String::from("Mempermainkan bukunya")), "main buku");
+/// ```
+pub struct TalaStemmer {
+    tokenizer: Tokenizer,
+}
+
+/// Kind of the removed first or second order prefix, which restricts the suffixes that can be removed
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum PrefixType {
+    None,
+    /// "di-", "me-" and "ter-"
+    DiMeTer,
+    /// "pe-" and "per-"
+    PePer,
+    /// "ke-" and "pe(N)-"
+    KePeng,
+    /// "be-" and "ber-"
+    BeBer,
+}
+
+/// Word being stemmed, along with its number of vowels
+struct TalaWord {
+    word: String,
+    measure: usize,
+    prefix_type: PrefixType,
+}
+
+impl TalaWord {
+    fn new(word: &str) -> Self {
+        Self {
+            word: word.to_string(),
+            measure: word.chars().filter(|c| is_vowel(*c)).count(),
+            prefix_type: PrefixType::None,
+        }
+    }
+
+    fn can_be_stemmed(&self) -> bool {
+        self.measure > 2
+    }
+
+    fn remove_suffix_of(&mut self, suffixes: &[&str]) -> bool {
+        match suffixes.iter().find(|suffix| self.word.ends_with(*suffix)) {
+            Some(suffix) => {
+                self.word.truncate(self.word.len() - suffix.len());
+                self.measure -= 1;
+                true
+            },
+            None => false,
+        }
+    }
+
+    fn remove_particle(&mut self) -> bool {
+        self.remove_suffix_of(&["kah", "lah", "pun"])
+    }
+
+    fn remove_possessive_pronoun(&mut self) -> bool {
+        self.remove_suffix_of(&["ku", "mu", "nya"])
+    }
+
+    fn remove_suffix(&mut self) -> bool {
+        let prefix_type = self.prefix_type;
+        if self.word.ends_with("kan") && prefix_type != PrefixType::KePeng && prefix_type != PrefixType::PePer {
+            return self.remove_suffix_of(&["kan"]);
+        }
+        if self.word.ends_with("an") && prefix_type != PrefixType::DiMeTer {
+            return self.remove_suffix_of(&["an"]);
+        }
+        let is_i_allowed = matches!(prefix_type, PrefixType::None | PrefixType::DiMeTer | PrefixType::PePer);
+        if self.word.ends_with('i') && !self.word.ends_with("si") && is_i_allowed {
+            return self.remove_suffix_of(&["i"]);
+        }
+        false
+    }
+
+    /// Replaces `prefix` with `replacement`, and keeps track of its type
+    fn replace_prefix(&mut self, prefix: &str, replacement: &str, prefix_type: PrefixType) -> bool {
+        self.word.replace_range(..prefix.len(), replacement);
+        self.measure -= 1;
+        self.prefix_type = prefix_type;
+        true
+    }
+
+    fn remove_first_order_prefix(&mut self) -> bool {
+        let followed_by_vowel = |prefix: &str| self.word.strip_prefix(prefix)
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(is_vowel);
+
+        // menyapu -> sapu, memukul -> pukul
+        for (prefix, prefix_type) in [("meny", PrefixType::DiMeTer), ("peny", PrefixType::KePeng)] {
+            if followed_by_vowel(prefix) {
+                return self.replace_prefix(prefix, "s", prefix_type);
+            }
+        }
+        for (prefix, prefix_type) in [("mem", PrefixType::DiMeTer), ("pem", PrefixType::KePeng)] {
+            if followed_by_vowel(prefix) {
+                return self.replace_prefix(prefix, "p", prefix_type);
+            }
+        }
+
+        let prefixes = [
+            ("meng", PrefixType::DiMeTer),
+            ("peng", PrefixType::KePeng),
+            ("meny", PrefixType::DiMeTer),
+            ("peny", PrefixType::KePeng),
+            ("mem", PrefixType::DiMeTer),
+            ("pem", PrefixType::KePeng),
+            ("men", PrefixType::DiMeTer),
+            ("pen", PrefixType::KePeng),
+            ("ter", PrefixType::DiMeTer),
+            ("me", PrefixType::DiMeTer),
+            ("di", PrefixType::DiMeTer),
+            ("ke", PrefixType::KePeng),
+        ];
+        match prefixes.iter().find(|(prefix, _)| self.word.starts_with(prefix)) {
+            Some((prefix, prefix_type)) => self.replace_prefix(prefix, "", *prefix_type),
+            None => false,
+        }
+    }
+
+    fn remove_second_order_prefix(&mut self) -> bool {
+        if self.word.starts_with("belajar") {
+            return self.replace_prefix("bel", "", PrefixType::BeBer);
+        }
+        if self.word.starts_with("ber") {
+            return self.replace_prefix("ber", "", PrefixType::BeBer);
+        }
+        if self.word.starts_with("per") {
+            return self.replace_prefix("per", "", PrefixType::PePer);
+        }
+        if self.word.starts_with("pe") {
+            return self.replace_prefix("pe", "", PrefixType::PePer);
+        }
+        // bekerja -> kerja
+        let mut rest = self.word.chars().skip(2);
+        let is_ker = rest.next().is_some_and(|c| !is_vowel(c)) && rest.next() == Some('e') && rest.next() == Some('r');
+        if self.word.starts_with("be") && is_ker {
+            return self.replace_prefix("be", "", PrefixType::BeBer);
+        }
+        false
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+impl TalaStemmer {
+    pub fn new() -> Self {
+        Self {
+            tokenizer: Tokenizer::new(),
+        }
+    }
+
+    /// Stem the given text.
+    ///
+    /// The text is split by the `Tokenizer`: punctuations are removed, while numbers and URLs are kept as they are.
+    pub fn stem(&self, text: String) -> String {
+        let stemmed_words: Vec<String> = self.tokenizer.tokenize(&text).into_iter()
+            .filter_map(|token| match token.kind {
+                TokenKind::Punctuation => None,
+                kind if kind.is_word() => Some(self.stem_word(&token.normalized)),
+                _ => Some(token.normalized),
+            })
+            .collect();
+        stemmed_words.join(" ")
+    }
+
+    /// Stem a single lowercase word.
+    ///
+    /// Words with at most two vowels are returned as-is.
+    pub fn stem_word(&self, word: &str) -> String {
+        let mut word = TalaWord::new(word);
+        if !word.can_be_stemmed() {
+            return word.word;
+        }
+
+        word.remove_particle();
+        if word.can_be_stemmed() {
+            word.remove_possessive_pronoun();
+        }
+        if !word.can_be_stemmed() {
+            return word.word;
+        }
+
+        if word.remove_first_order_prefix() {
+            // the second order prefix is only removed along with a suffix, e.g. "mem-per-main-kan"
+            if word.can_be_stemmed() && word.remove_suffix() && word.can_be_stemmed() {
+                word.remove_second_order_prefix();
+            }
+        } else {
+            word.remove_second_order_prefix();
+            if word.can_be_stemmed() {
+                word.remove_suffix();
+            }
+        }
+        word.word
+    }
+}
+
+impl Default for TalaStemmer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tala_stemmer_test {
+    use super::*;
+
+    fn stem(word: &str) -> String {
+        TalaStemmer::new().stem_word(word)
+    }
+
+    #[test]
+    fn should_count_vowels() {
+        assert_eq!(TalaWord::new("mempermainkan").measure, 5);
+        assert_eq!(TalaWord::new("buku").measure, 2);
+    }
+
+    #[test]
+    fn should_not_stem_word_with_two_vowels() {
+        assert_eq!(stem("buku"), "buku");
+        assert_eq!(stem("makan"), "makan");
+        assert_eq!(stem("dapat"), "dapat");
+    }
+
+    #[test]
+    fn should_remove_particle_and_possessive_pronoun() {
+        assert_eq!(stem("bukunya"), "buku");
+        assert_eq!(stem("bukunyalah"), "buku");
+        assert_eq!(stem("bukumu"), "buku");
+        assert_eq!(stem("bacalah"), "baca");
+    }
+
+    #[test]
+    fn should_remove_first_order_prefix() {
+        assert_eq!(stem("membaca"), "baca");
+        assert_eq!(stem("dibaca"), "baca");
+        assert_eq!(stem("terbawa"), "bawa");
+        assert_eq!(stem("mengambil"), "ambil");
+        assert_eq!(stem("ketiga"), "tiga");
+    }
+
+    #[test]
+    fn should_recode_first_order_prefix() {
+        assert_eq!(stem("menyapu"), "sapu");
+        assert_eq!(stem("memukul"), "pukul");
+        assert_eq!(stem("penyapu"), "sapu");
+        assert_eq!(stem("pemukul"), "pukul");
+    }
+
+    #[test]
+    fn should_remove_second_order_prefix() {
+        assert_eq!(stem("bermain"), "main");
+        assert_eq!(stem("perbesar"), "besar");
+        assert_eq!(stem("pelajar"), "lajar");
+        assert_eq!(stem("belajar"), "ajar");
+        assert_eq!(stem("bekerja"), "kerja");
+    }
+
+    #[test]
+    fn should_remove_suffix_allowed_by_prefix() {
+        assert_eq!(stem("mainkan"), "main");
+        assert_eq!(stem("pembangunan"), "bangun");
+        assert_eq!(stem("mempermainkan"), "main");
+        assert_eq!(stem("memperbaiki"), "baik");
+        assert_eq!(stem("berjualan"), "jual");
+
+        // "-an" is not removed after "di-", "me-" or "ter-"
+        assert_eq!(stem("dimakan"), "makan");
+        // "-i" is not removed after "ke-" or "pe(N)-", nor after "s"
+        assert_eq!(stem("ketahui"), "tahui");
+        assert_eq!(stem("televisi"), "televisi");
+    }
+
+    #[test]
+    fn should_stem_text() {
+        let stemmer = TalaStemmer::new();
+        assert_eq!(stemmer.stem(String::from("Dia membaca, lalu menyapu!")), "dia baca lalu sapu");
+    }
+}
@@ -1,14 +1,18 @@
 use crate::dictionary::Dictionary;
+use crate::error::Result;
+use crate::lexicon::Lexicon;
+use crate::tokenizer::Tokenizer;
 
 pub struct StopWordRemover {
-    dictionary: Dictionary,
+    dictionary: Box<dyn Lexicon>,
+    tokenizer: Tokenizer,
 }
 
 impl StopWordRemover {
     /// Initialize StopWordRemover with default stop word dictionary.
     pub fn new() -> Self {
         Self {
-            dictionary: Dictionary::from_list(
+            dictionary: Box::new(Dictionary::from_list(
                 vec![
                     "yang", "untuk", "pada", "ke", "para", "namun", "menurut", "antara", "dia",
                     "dua", "ia", "seperti", "jika", "jika", "sehingga", "kembali", "dan", "tidak",
@@ -27,26 +31,61 @@ impl StopWordRemover {
                     "pasti", "saja", "toh", "ya", "walau", "tolong", "tentu", "amat", "apalagi",
                     "bagaimanapun",
                 ]
-            )
+            )),
+            tokenizer: Tokenizer::new(),
         }
     }
 
     /// Initialize StopWordRemover with given stop word dictionary.
     pub fn from(stop_word_dictionary: Dictionary) -> Self {
+        Self::with_lexicon(stop_word_dictionary)
+    }
+
+    /// Initialize StopWordRemover with given stop word store, e.g. a shared `Arc<HashSet<String>>`.
+    pub fn with_lexicon(lexicon: impl Lexicon + 'static) -> Self {
         Self {
-            dictionary: stop_word_dictionary
+            dictionary: Box::new(lexicon),
+            tokenizer: Tokenizer::new(),
         }
     }
 
+    /// Initialize StopWordRemover with stop word dictionary read from a text file,
+    /// or returns an error if the file cannot be read.
+    pub fn try_from_file(filename: &str) -> Result<Self> {
+        Ok(Self::from(Dictionary::try_from_file(filename)?))
+    }
+
     /// Remove stop word.
+    ///
+    /// The text is split by the `Tokenizer`, the same way as `Stemmer` does.
+    /// Every other token, including punctuations, is kept as written, and whitespaces are collapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustrawi::stop_word_remover::StopWordRemover;
+    /// let stop_word_remover = StopWordRemover::new();
+    /// assert_eq!(stop_word_remover.remove(String::from("Kucing dan ayam, itu bermain.")), "Kucing ayam, bermain.");
+    /// ```
     pub fn remove(&self, text: String) -> String {
-        let filtered_words: Vec<_> = text.split_whitespace().into_iter().filter(
-            |x| {
-                !self.dictionary.contains(x)
+        let mut remaining_text = String::with_capacity(text.len());
+        let mut start = 0;
+        for token in self.tokenizer.tokenize(&text) {
+            if token.kind.is_word() && self.dictionary.contains(&token.normalized) {
+                remaining_text.push_str(&text[start..token.span.start]);
+                start = token.span.end;
             }
-        ).collect::<_>();
-        let joined_string = filtered_words.join(" ");
-        joined_string
+        }
+        remaining_text.push_str(&text[start..]);
+
+        let words: Vec<&str> = remaining_text.split_whitespace().collect();
+        words.join(" ")
+    }
+}
+
+impl Default for StopWordRemover {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -75,4 +114,39 @@ mod stop_word_remover_test {
         let clean_string = stop_word_remover.remove(string);
         assert_eq!(clean_string, "Kucing ayam");
     }
+
+    #[test]
+    fn should_remove_stop_word_followed_by_punctuation() {
+        let stop_word_remover = StopWordRemover::new();
+        let string = String::from("Kucing bermain, dan (ayam) juga.");
+
+        let clean_string = stop_word_remover.remove(string);
+        assert_eq!(clean_string, "Kucing bermain, (ayam) .");
+    }
+
+    #[test]
+    fn should_not_remove_stop_word_inside_hyphenated_compound() {
+        let stop_word_remover = StopWordRemover::new();
+        let clean_string = stop_word_remover.remove(String::from("ke-2 dan dia-dia"));
+        assert_eq!(clean_string, "ke-2 dia-dia");
+    }
+
+    #[test]
+    fn should_remove_stop_word_of_lexicon() {
+        let stop_words = std::collections::HashSet::from([String::from("dan")]);
+        let stop_word_remover = StopWordRemover::with_lexicon(stop_words);
+        assert_eq!(stop_word_remover.remove(String::from("Kucing DAN ayam")), "Kucing ayam");
+    }
+
+    #[test]
+    fn should_try_to_initialize_from_file() {
+        let stop_word_remover = StopWordRemover::try_from_file("tests/example_word_list").unwrap();
+        let clean_string = stop_word_remover.remove(String::from("kucing dan ayam"));
+        assert_eq!(clean_string, "dan");
+    }
+
+    #[test]
+    fn should_return_error_on_invalid_file() {
+        assert_eq!(StopWordRemover::try_from_file("tests/invalid_file").is_err(), true);
+    }
 }
@@ -0,0 +1,223 @@
+use std::ops::Range;
+use regex::Regex;
+
+/// Kind of a token, as recognized by the `Tokenizer`
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TokenKind {
+    /// Letters and digits, starting with a letter, e.g. "membaca", "covid19" or "qur'an"
+    Word,
+    /// Digits, optionally separated by "." or ",", e.g. "2024" or "3,14"
+    Number,
+    /// Words joined by "-", e.g. "buku-buku" or "nikmat-Nya"
+    HyphenatedCompound,
+    /// Web address, starting with "http://", "https://" or "www."
+    Url,
+    /// Any other single character, except whitespace
+    Punctuation,
+}
+
+impl TokenKind {
+    /// Returns true if the token can be stemmed, i.e. a word or a hyphenated compound
+    pub fn is_word(&self) -> bool {
+        matches!(self, TokenKind::Word | TokenKind::HyphenatedCompound)
+    }
+}
+
+/// A token of the text, along with its location
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Token<'a> {
+    /// The token as written in the text
+    pub surface: &'a str,
+    /// Byte range of the token in the text
+    pub span: Range<usize>,
+    pub kind: TokenKind,
+    /// Lowercased word and hyphenated compound, other tokens are kept as written
+    pub normalized: String,
+}
+
+/// Splits a text into tokens, keeping the location of each token.
+///
+/// Shared by `Stemmer` and `StopWordRemover`, so both see the same words.
+///
+/// # Examples
+///
+/// ```
+/// use rustrawi::tokenizer::{Tokenizer, TokenKind};
+/// let tokenizer = Tokenizer::new();
+/// let tokens = tokenizer.tokenize("Buku-buku itu 2 kali lebih murah!");
+/// assert_eq!(tokens[0].kind, TokenKind::HyphenatedCompound);
+/// assert_eq!(tokens[0].normalized, "buku-buku");
+/// assert_eq!(tokens[2].kind, TokenKind::Number);
+/// assert_eq!(tokens[6].span, 32..33);
+/// ```
+pub struct Tokenizer {
+    re_url: Regex,
+}
+
+impl Tokenizer {
+    pub fn new() -> Self {
+        Self {
+            re_url: Regex::new(r"^(?i:https?://|www\.)\S+").unwrap(),
+        }
+    }
+
+    /// Returns every token of the text, in order. Whitespaces are skipped.
+    pub fn tokenize<'a>(&self, text: &'a str) -> Vec<Token<'a>> {
+        let mut tokens = vec![];
+        let mut start = 0;
+        while let Some((offset, c)) = text[start..].char_indices().find(|(_, c)| !c.is_whitespace()) {
+            start += offset;
+            let (end, kind) = match self.match_url(&text[start..]) {
+                Some(length) => (start + length, TokenKind::Url),
+                None if c.is_alphanumeric() => self.match_word(text, start),
+                None => (start + c.len_utf8(), TokenKind::Punctuation),
+            };
+            let surface = &text[start..end];
+            let normalized = if kind.is_word() { surface.to_lowercase() } else { surface.to_string() };
+            tokens.push(Token { surface, span: start..end, kind, normalized });
+            start = end;
+        }
+        tokens
+    }
+
+    /// Returns the length of the URL at the beginning of the text, without its trailing punctuation
+    fn match_url(&self, text: &str) -> Option<usize> {
+        let url = self.re_url.find(text)?.as_str();
+        let url = url.trim_end_matches(|c: char| ".,;:!?'\")]}".contains(c));
+        Some(url.len())
+    }
+
+    /// Returns the end and the kind of the word or number starting at `start`.
+    ///
+    /// A "-", "'", "." or "," is part of the token only if it is surrounded by letters or digits.
+    fn match_word(&self, text: &str, start: usize) -> (usize, TokenKind) {
+        let mut kind = TokenKind::Number;
+        let mut end = start;
+        let mut chars = text[start..].char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            let is_followed_by_alphanumeric = chars.peek().is_some_and(|(_, next)| next.is_alphanumeric());
+            match c {
+                c if c.is_numeric() => {},
+                c if c.is_alphanumeric() => {
+                    if kind == TokenKind::Number {
+                        kind = TokenKind::Word;
+                    }
+                },
+                '-' if is_followed_by_alphanumeric => kind = TokenKind::HyphenatedCompound,
+                '\'' | '\u{2019}' if is_followed_by_alphanumeric && kind != TokenKind::Number => {},
+                '.' | ',' if kind == TokenKind::Number && chars.peek().is_some_and(|(_, next)| next.is_numeric()) => {},
+                _ => break,
+            }
+            end = start + offset + c.len_utf8();
+        }
+        (end, kind)
+    }
+}
+
+impl Default for Tokenizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tokenizer_test {
+    use super::*;
+
+    fn kinds(text: &str) -> Vec<(String, TokenKind)> {
+        Tokenizer::new().tokenize(text).into_iter()
+            .map(|token| (token.surface.to_string(), token.kind))
+            .collect()
+    }
+
+    #[test]
+    fn should_split_words_and_punctuations() {
+        assert_eq!(kinds("Ayam, kambing; (kucing)."), vec![
+            (String::from("Ayam"), TokenKind::Word),
+            (String::from(","), TokenKind::Punctuation),
+            (String::from("kambing"), TokenKind::Word),
+            (String::from(";"), TokenKind::Punctuation),
+            (String::from("("), TokenKind::Punctuation),
+            (String::from("kucing"), TokenKind::Word),
+            (String::from(")"), TokenKind::Punctuation),
+            (String::from("."), TokenKind::Punctuation),
+        ]);
+    }
+
+    #[test]
+    fn should_recognize_numbers() {
+        assert_eq!(kinds("2024 3,14 1.000.000 covid19 19an"), vec![
+            (String::from("2024"), TokenKind::Number),
+            (String::from("3,14"), TokenKind::Number),
+            (String::from("1.000.000"), TokenKind::Number),
+            (String::from("covid19"), TokenKind::Word),
+            (String::from("19an"), TokenKind::Word),
+        ]);
+        assert_eq!(kinds("2024."), vec![
+            (String::from("2024"), TokenKind::Number),
+            (String::from("."), TokenKind::Punctuation),
+        ]);
+    }
+
+    #[test]
+    fn should_recognize_hyphenated_compounds() {
+        assert_eq!(kinds("nikmat-nikmat-Nya ke-2 buku- -"), vec![
+            (String::from("nikmat-nikmat-Nya"), TokenKind::HyphenatedCompound),
+            (String::from("ke-2"), TokenKind::HyphenatedCompound),
+            (String::from("buku"), TokenKind::Word),
+            (String::from("-"), TokenKind::Punctuation),
+            (String::from("-"), TokenKind::Punctuation),
+        ]);
+    }
+
+    #[test]
+    fn should_keep_apostrophe_inside_word() {
+        assert_eq!(kinds("Qur'an ma’af 'kata' 90'an"), vec![
+            (String::from("Qur'an"), TokenKind::Word),
+            (String::from("ma’af"), TokenKind::Word),
+            (String::from("'"), TokenKind::Punctuation),
+            (String::from("kata"), TokenKind::Word),
+            (String::from("'"), TokenKind::Punctuation),
+            (String::from("90"), TokenKind::Number),
+            (String::from("'"), TokenKind::Punctuation),
+            (String::from("an"), TokenKind::Word),
+        ]);
+    }
+
+    #[test]
+    fn should_recognize_urls() {
+        assert_eq!(kinds("Lihat https://example.com/a?b=c, atau www.example.com."), vec![
+            (String::from("Lihat"), TokenKind::Word),
+            (String::from("https://example.com/a?b=c"), TokenKind::Url),
+            (String::from(","), TokenKind::Punctuation),
+            (String::from("atau"), TokenKind::Word),
+            (String::from("www.example.com"), TokenKind::Url),
+            (String::from("."), TokenKind::Punctuation),
+        ]);
+    }
+
+    #[test]
+    fn should_keep_byte_offsets() {
+        let text = "  Kafé   Jalan-jalan! ";
+        let tokens = Tokenizer::new().tokenize(text);
+        for token in &tokens {
+            assert_eq!(&text[token.span.clone()], token.surface);
+        }
+        assert_eq!(tokens[0].span, 2..7);
+        assert_eq!(tokens[1].span, 10..21);
+    }
+
+    #[test]
+    fn should_normalize_words_only() {
+        let tokens = Tokenizer::new().tokenize("Buku-Buku WWW.Example.com");
+        assert_eq!(tokens[0].normalized, "buku-buku");
+        assert_eq!(tokens[1].normalized, "WWW.Example.com");
+    }
+
+    #[test]
+    fn should_return_empty_list_on_blank_text() {
+        assert_eq!(Tokenizer::new().tokenize(" \t\n").len(), 0);
+    }
+}
@@ -0,0 +1,151 @@
+/// Returns the word and expected stem of every line of a reference or snapshot, skipping the "#" comments.
+///
+/// The flag is true if the line is marked with "! ", see `algorithm_reference_test`.
+#[cfg(test)]
+fn entries(lines: &str) -> impl Iterator<Item = (&str, &str, bool)> {
+    lines.lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| match line.strip_prefix("! ") {
+            Some(line) => line.split_once(' ').map(|(word, stem)| (word, stem, true)),
+            None => line.split_once(' ').map(|(word, stem)| (word, stem, false)),
+        })
+}
+
+#[cfg(test)]
+/// Reference tests: each file under `tests/reference` lists the published examples of the step an algorithm adds,
+/// and every algorithm is checked against its own examples and the ones of the algorithms it extends.
+///
+/// The dictionary only contains the expected stems, so a failure comes from the algorithm, not from the dictionary.
+/// A line marked with "! " gives another stem with the bundled dictionary, as explained by the comment above it.
+mod algorithm_reference_test {
+    use rustrawi::dictionary::Dictionary;
+    use rustrawi::stemmer::Stemmer;
+    use rustrawi::stemmer::algorithm::Algorithm;
+    use super::entries;
+
+    /// References of each algorithm, in the order the algorithms extend each other
+    const REFERENCES: [(Algorithm, &str); 4] = [
+        (Algorithm::NaziefAdriani, include_str!("reference/nazief_adriani.txt")),
+        (Algorithm::ConfixStripping, include_str!("reference/confix_stripping.txt")),
+        (Algorithm::EnhancedConfixStripping, include_str!("reference/enhanced_confix_stripping.txt")),
+        (Algorithm::ModifiedEnhancedConfixStripping, include_str!("reference/modified_enhanced_confix_stripping.txt")),
+    ];
+
+    /// Returns the entries of the references of the given algorithm and of the algorithms it extends
+    fn references_of(algorithm: Algorithm) -> impl Iterator<Item = (&'static str, &'static str, bool)> {
+        let count = REFERENCES.iter().position(|(reference_algorithm, _)| *reference_algorithm == algorithm).unwrap() + 1;
+        REFERENCES[..count].iter().flat_map(|(_, reference)| entries(reference))
+    }
+
+    /// Stems every word of the references of the algorithm, and returns the lines whose stem differs from the expected one
+    fn check_reference(algorithm: Algorithm) -> Vec<String> {
+        let stems = REFERENCES.iter().flat_map(|(_, reference)| entries(reference)).map(|(_, stem, _)| stem).collect();
+        let stemmer = Stemmer::builder().dictionary(Dictionary::from_list(stems)).algorithm(algorithm).build();
+        references_of(algorithm)
+            .filter_map(|(word, expected_stem, _)| {
+                let stem = stemmer.stem(word.to_string());
+                if stem == expected_stem {
+                    None
+                } else {
+                    Some(format!("{}: expected {}, got {}", word, expected_stem, stem))
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_match_nazief_adriani_reference() {
+        assert_eq!(check_reference(Algorithm::NaziefAdriani), Vec::<String>::new());
+    }
+
+    #[test]
+    fn should_match_confix_stripping_reference() {
+        assert_eq!(check_reference(Algorithm::ConfixStripping), Vec::<String>::new());
+    }
+
+    #[test]
+    fn should_match_enhanced_confix_stripping_reference() {
+        assert_eq!(check_reference(Algorithm::EnhancedConfixStripping), Vec::<String>::new());
+    }
+
+    #[test]
+    fn should_match_modified_enhanced_confix_stripping_reference() {
+        assert_eq!(check_reference(Algorithm::ModifiedEnhancedConfixStripping), Vec::<String>::new());
+    }
+
+    /// A marked line must keep failing, so the mark is removed once the stem matches
+    #[cfg(feature = "bundled-dictionary")]
+    #[test]
+    fn should_match_reference_with_bundled_dictionary_unless_marked() {
+        let stemmer = Stemmer::new();
+        let unexpected: Vec<String> = references_of(Algorithm::ModifiedEnhancedConfixStripping)
+            .filter_map(|(word, expected_stem, is_marked)| {
+                let stem = stemmer.stem(word.to_string());
+                match (stem == expected_stem, is_marked) {
+                    (true, true) => Some(format!("{}: marked, but got {}", word, stem)),
+                    (false, false) => Some(format!("{}: expected {}, got {}", word, expected_stem, stem)),
+                    _ => None,
+                }
+            })
+            .collect();
+        assert_eq!(unexpected, Vec::<String>::new());
+    }
+}
+
+#[cfg(all(test, feature = "bundled-dictionary"))]
+/// Snapshot regression tests, secondary to the reference tests: each file under `tests/snapshot` records the stems
+/// currently returned by one algorithm with the bundled dictionary, so any change of behavior shows up as a diff.
+/// They are not reference results, and a line marked with "! " is a known wrong stem, see the comment above it.
+mod algorithm_snapshot_test {
+    use rustrawi::stemmer::Stemmer;
+    use rustrawi::stemmer::algorithm::Algorithm;
+    use super::entries;
+
+    /// Stems every word of the snapshot, and returns the lines whose stem differs from the recorded one
+    fn check_snapshot(algorithm: Algorithm, snapshot: &str) -> Vec<String> {
+        let stemmer = Stemmer::builder().algorithm(algorithm).build();
+        entries(snapshot)
+            .filter_map(|(word, expected_stem, _)| {
+                let stem = stemmer.stem(word.to_string());
+                if stem == expected_stem {
+                    None
+                } else {
+                    Some(format!("{}: expected {}, got {}", word, expected_stem, stem))
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_match_nazief_adriani_snapshot() {
+        let snapshot = include_str!("snapshot/nazief_adriani.txt");
+        assert_eq!(check_snapshot(Algorithm::NaziefAdriani, snapshot), Vec::<String>::new());
+    }
+
+    #[test]
+    fn should_match_confix_stripping_snapshot() {
+        let snapshot = include_str!("snapshot/confix_stripping.txt");
+        assert_eq!(check_snapshot(Algorithm::ConfixStripping, snapshot), Vec::<String>::new());
+    }
+
+    #[test]
+    fn should_match_enhanced_confix_stripping_snapshot() {
+        let snapshot = include_str!("snapshot/enhanced_confix_stripping.txt");
+        assert_eq!(check_snapshot(Algorithm::EnhancedConfixStripping, snapshot), Vec::<String>::new());
+    }
+
+    #[test]
+    fn should_match_modified_enhanced_confix_stripping_snapshot() {
+        let snapshot = include_str!("snapshot/modified_enhanced_confix_stripping.txt");
+        assert_eq!(check_snapshot(Algorithm::ModifiedEnhancedConfixStripping, snapshot), Vec::<String>::new());
+    }
+
+    #[test]
+    fn should_use_modified_enhanced_confix_stripping_by_default() {
+        let snapshot = include_str!("snapshot/modified_enhanced_confix_stripping.txt");
+        let stemmer = Stemmer::new();
+        for (word, expected_stem, _) in entries(snapshot) {
+            assert_eq!(stemmer.stem(word.to_string()), expected_stem);
+        }
+    }
+}
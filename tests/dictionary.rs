@@ -1,3 +1,5 @@
+#![allow(clippy::bool_assert_comparison)]
+
 #[cfg(test)]
 mod dictionary_test {
     use rustrawi::dictionary::Dictionary;
@@ -32,7 +34,13 @@ mod dictionary_test {
     #[test]
     #[should_panic(expected="No such file or directory (os error 2)")]
     fn should_panic_with_invalid_file() {
-        let dictionary = Dictionary::from_file("invalid_path");
+        let _dictionary = Dictionary::from_file("invalid_path");
+    }
+
+    #[test]
+    fn should_return_error_with_invalid_file() {
+        let result = Dictionary::try_from_file("invalid_path");
+        assert_eq!(matches!(result, Err(rustrawi::Error::Io(_))), true);
     }
 
     #[test]
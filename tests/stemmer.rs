@@ -1,11 +1,45 @@
-#[cfg(test)]
+#[cfg(all(test, feature = "bundled-dictionary"))]
 mod stemmer_test {
     use rustrawi::stemmer::Stemmer;
 
     #[test]
-    #[ignore]
     fn should_initialize_stemmer_with_default_dictionary() {
         let stemmer = Stemmer::new();
         assert_eq!(stemmer.len(), 29932);
     }
+
+    /// Runs again in a child process started outside of the crate root,
+    /// so the working directory of the test process is never changed.
+    #[test]
+    fn should_initialize_stemmer_outside_of_crate_root() {
+        if std::env::var_os("RUSTRAWI_OUTSIDE_CRATE_ROOT").is_some() {
+            let stemmer = Stemmer::new();
+            assert_eq!(stemmer.len(), 29932);
+            assert_eq!(stemmer.stem(String::from("Membahagiakan")), "bahagia");
+            return;
+        }
+
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", "stemmer_test::should_initialize_stemmer_outside_of_crate_root"])
+            .env("RUSTRAWI_OUTSIDE_CRATE_ROOT", "1")
+            .current_dir(std::env::temp_dir())
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "{}", stdout);
+        assert!(stdout.contains("1 passed"), "{}", stdout);
+    }
+}
+
+#[cfg(test)]
+mod stemmer_from_test {
+    use rustrawi::dictionary::Dictionary;
+    use rustrawi::stemmer::Stemmer;
+
+    #[test]
+    fn should_initialize_stemmer_with_custom_dictionary() {
+        let stemmer = Stemmer::from(Dictionary::from_list(vec!["baca", "tulis"]));
+        assert_eq!(stemmer.len(), 2);
+        assert_eq!(stemmer.stem(String::from("membaca dan menulis")), "baca dan tulis");
+    }
 }
\ No newline at end of file
@@ -20,4 +20,21 @@ mod stop_word_remover_test {
         let removed = stop_word_remover.remove(string);
         assert_eq!(removed, "Kucing dan Ayam akrab")
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tokenizer_consistency_test {
+    use rustrawi::dictionary::Dictionary;
+    use rustrawi::stemmer::Stemmer;
+    use rustrawi::stop_word_remover::StopWordRemover;
+
+    #[test]
+    fn should_split_text_the_same_way_as_stemmer() {
+        let stop_word_remover = StopWordRemover::new();
+        let stemmer = Stemmer::from(Dictionary::from_list(vec!["main", "kucing", "ayam"]));
+
+        let removed = stop_word_remover.remove(String::from("Kucing, dan ayam itu bermain-main."));
+        assert_eq!(removed, "Kucing, ayam bermain-main.");
+        assert_eq!(stemmer.stem(removed), "kucing ayam main");
+    }
+}
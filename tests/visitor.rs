@@ -0,0 +1,50 @@
+#![allow(clippy::bool_assert_comparison)]
+
+#[cfg(test)]
+mod custom_visitor_test {
+    use rustrawi::dictionary::Dictionary;
+    use rustrawi::stemmer::Stemmer;
+    use rustrawi::stemmer::context::Context;
+    use rustrawi::stemmer::context::removal::AffixType;
+    use rustrawi::stemmer::context::visitor::{Visitor, VisitorResult, VisitorType};
+
+    /// Removes the colloquial "nge-" prefix, e.g. "ngegas" -> "gas"
+    struct RemoveNgePrefix;
+
+    impl Visitor for RemoveNgePrefix {
+        fn get_visitor_type(&self) -> VisitorType {
+            VisitorType::PrefixVisitor
+        }
+
+        fn visit(&self, context: &mut Context) -> VisitorResult {
+            if let Some(word) = context.get_current_word().strip_prefix("nge") {
+                let word = word.to_string();
+                context.remove_affix(self, word, AffixType::DerivationalPrefix);
+            }
+            VisitorResult::DoNothing
+        }
+    }
+
+    fn stemmer(dictionary: Dictionary) -> Stemmer {
+        Stemmer::builder()
+            .dictionary(dictionary)
+            .insert_visitor(VisitorType::PrefixVisitor, 0, Box::new(RemoveNgePrefix))
+            .build()
+    }
+
+    #[test]
+    fn should_stem_with_custom_visitor() {
+        let stemmer = stemmer(Dictionary::from_list(vec!["gas", "baca"]));
+        assert_eq!(stemmer.stem(String::from("ngegas membaca")), "gas baca");
+    }
+
+    #[test]
+    fn should_record_custom_visitor_removal() {
+        let stemmer = stemmer(Dictionary::from_list(vec!["gas"]));
+        let derivation = stemmer.stem_word_explained("ngegas");
+        let removals = derivation.get_removals();
+        assert_eq!(removals.len(), 1);
+        assert_eq!(removals[0].get_visitor_name(), "RemoveNgePrefix");
+        assert_eq!(removals[0].get_removed_part(), "nge");
+    }
+}